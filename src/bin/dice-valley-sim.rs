@@ -0,0 +1,212 @@
+use dice_valley::game::{
+    cli::{parse_number, print_table},
+    config::{load_config, GameConfig},
+    landmarks::get_landmark_title,
+    simulation::{
+        create_lineup, create_simulated_strategy, run_simulation, SimulationError, SimulationReport,
+    },
+    tournament::{run_tournament, TournamentReport},
+};
+use std::{env, process};
+
+const USAGE: &str = "Usage: dice-valley-sim --lineup <ids> [--games <number>] [--seed <number>]
+                       [--config <path>]
+       dice-valley-sim tournament --lineup <ids> [--players <number>] [--games <number>]
+                       [--seed <number>] [--config <path>]
+Strategies: random, greedy, mcts";
+
+// Options passed on the command line.
+struct Options {
+    // Play every seating of the lineup's strategies instead of the lineup itself.
+    tournament: bool,
+    lineup: Vec<String>,
+    // Players at each tournament game.
+    table_size: usize,
+    game_count: usize,
+    seed: Option<u64>,
+    config_path: Option<String>,
+}
+
+fn main() {
+    let options = parse_options(env::args().skip(1)).unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        eprintln!("{}", USAGE);
+        process::exit(1);
+    });
+
+    let config = match &options.config_path {
+        Some(path) => load_config(path),
+        None => Ok(GameConfig::default()),
+    }
+    .unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+
+    // A tournament's strategies are only seated a table at a time.
+    let checked = if options.tournament {
+        options
+            .lineup
+            .iter()
+            .try_for_each(|id| create_simulated_strategy(id).map(|_| ()))
+    } else {
+        create_lineup(&options.lineup, &config).map(|_| ())
+    };
+    if let Err(error) = checked {
+        eprintln!("{}", error);
+        eprintln!("{}", USAGE);
+        process::exit(1);
+    }
+
+    let seed = options.seed.unwrap_or_else(rand::random);
+    if options.tournament {
+        println!(
+            "Playing {} games of every seating of {} at tables of {} with seed {}.",
+            options.game_count,
+            options.lineup.join(", "),
+            options.table_size,
+            seed
+        );
+        let report = run_tournament(
+            &options.lineup,
+            &config,
+            options.table_size,
+            options.game_count,
+            seed,
+        )
+        .unwrap_or_else(|error| exit_with_error(&error));
+        show_leaderboard(&report);
+        return;
+    }
+
+    println!(
+        "Simulating {} games of {} with seed {}.",
+        options.game_count,
+        options.lineup.join(", "),
+        seed
+    );
+    let report = run_simulation(&options.lineup, &config, options.game_count, seed)
+        .unwrap_or_else(|error| exit_with_error(&error));
+    show_report(&report, &config);
+}
+
+fn exit_with_error(error: &SimulationError) -> ! {
+    eprintln!("The simulation cannot continue: {}", error);
+    process::exit(1);
+}
+
+fn show_leaderboard(report: &TournamentReport) {
+    println!("Games: {}", report.games);
+    let data = report
+        .get_standings()
+        .into_iter()
+        .enumerate()
+        .map(|(index, standing)| {
+            vec![
+                (index + 1).to_string(),
+                standing.strategy,
+                format!("{:.0}", standing.elo),
+                format!("±{:.0}", standing.elo_margin),
+                standing.games.to_string(),
+                standing.wins.to_string(),
+                format!(
+                    "{:.1}%",
+                    standing.wins as f64 / standing.games.max(1) as f64 * 100.0
+                ),
+            ]
+        })
+        .collect();
+    print_table(
+        "Leaderboard",
+        vec![
+            "Rank", "Strategy", "Elo", "95% CI", "Games", "Wins", "Win rate",
+        ],
+        data,
+    );
+}
+
+fn show_report(report: &SimulationReport, config: &GameConfig) {
+    println!(
+        "Games: {}, average length: {:.1} turns",
+        report.games,
+        report.get_average_turns()
+    );
+
+    let data = report
+        .seats
+        .iter()
+        .enumerate()
+        .map(|(turn, seat)| {
+            vec![
+                (turn + 1).to_string(),
+                seat.strategy.clone(),
+                seat.wins.to_string(),
+                format!("{:.1}%", report.get_win_rate(turn) * 100.0),
+                format!("{:.1}", report.get_average_coins(turn)),
+            ]
+        })
+        .collect();
+    print_table(
+        "Results",
+        vec!["Seat", "Strategy", "Wins", "Win rate", "Average coins"],
+        data,
+    );
+
+    // Each landmark shows the average turn it was built on and how often it was built at all.
+    let landmarks = config.get_landmarks();
+    let data = report
+        .seats
+        .iter()
+        .enumerate()
+        .map(|(turn, seat)| {
+            let mut row = vec![(turn + 1).to_string(), seat.strategy.clone()];
+            row.extend(landmarks.iter().map(|landmark| {
+                match report.get_landmark_timing(turn, landmark) {
+                    Some((share, average_turn)) => {
+                        format!("{:.1} ({:.0}%)", average_turn, share * 100.0)
+                    }
+                    None => "-".to_string(),
+                }
+            }));
+            row
+        })
+        .collect();
+    let mut columns = vec!["Seat", "Strategy"];
+    columns.extend(landmarks.iter().map(get_landmark_title));
+    print_table("Landmark turns", columns, data);
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        tournament: false,
+        lineup: Vec::new(),
+        table_size: 2,
+        game_count: 100,
+        seed: None,
+        config_path: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--lineup" => {
+                options.lineup = args
+                    .next()
+                    .ok_or("Expected strategy ids after --lineup.")?
+                    .split(',')
+                    .map(|id| id.trim().to_string())
+                    .collect();
+            }
+            "--players" => options.table_size = parse_number(&arg, args.next())?,
+            "tournament" => options.tournament = true,
+            "--games" => options.game_count = parse_number(&arg, args.next())?,
+            "--seed" => options.seed = Some(parse_number(&arg, args.next())?),
+            "--config" => {
+                options.config_path = Some(args.next().ok_or("Expected a path after --config.")?);
+            }
+            _ => return Err(format!("Unknown argument \"{}\".", arg)),
+        }
+    }
+    if options.lineup.is_empty() {
+        return Err("Expected a lineup, e.g. --lineup random,greedy.".to_string());
+    }
+    Ok(options)
+}
@@ -0,0 +1,423 @@
+use super::{
+    cards::{CardKind, CardStack},
+    controller::{
+        count_player_icons, get_activatable_cards, get_legal_roll_two_dice,
+        get_shopping_mall_bonus, PlayerCardStack,
+    },
+    player::Player,
+    registry::{get_card_definition, CardEffect},
+};
+
+// What rolling one or two dice on their own turn is expected to bring a player.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RollChoice {
+    pub roll_two_dice: bool,
+    // The coins the roll is expected to gain, or lose when negative.
+    pub expected_coins: f64,
+    // The chance that at least one of the player's establishments activates.
+    pub activation_chance: f64,
+}
+
+// The expected coins a player gains, or loses when negative, on each turn of a round.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncomeForecast {
+    // Each number of dice the player may roll on their own turn.
+    pub own_turn: Vec<RollChoice>,
+    // Each other player's turn, and what the player expects from it.
+    pub other_turns: Vec<(usize, f64)>,
+}
+
+impl IncomeForecast {
+    // The number of dice that pays the most on the player's own turn, and what it pays.
+    pub fn get_best_own_turn(&self) -> RollChoice {
+        get_best_roll_choice(&self.own_turn)
+    }
+
+    // The coins the player expects over a round, rolling the best number of dice on their turn.
+    pub fn get_round_total(&self) -> f64 {
+        self.get_best_own_turn().expected_coins
+            + self.other_turns.iter().map(|(_, coins)| coins).sum::<f64>()
+    }
+}
+
+// The exact chance of rolling each total with one or two dice.
+pub fn get_roll_chances(roll_two_dice: bool) -> Vec<(u8, f64)> {
+    if roll_two_dice {
+        (2..=12)
+            .map(|total| (total, f64::from(6 - u8::abs_diff(total, 7)) / 36.0))
+            .collect()
+    } else {
+        (1..=6).map(|total| (total, 1.0 / 6.0)).collect()
+    }
+}
+
+// Forecasts the player's income, assuming every other player rolls the number of dice that pays
+// them the most.
+pub fn get_income_forecast(players: &[Player], turn: usize) -> IncomeForecast {
+    IncomeForecast {
+        own_turn: get_roll_choices(players, turn),
+        other_turns: players
+            .iter()
+            .filter(|roller| roller.turn != turn)
+            .map(|roller| {
+                let roll_two_dice = get_best_roll_two_dice(players, roller.turn);
+                let coins = get_expected_roll_outcome(players, turn, roller.turn, roll_two_dice);
+                (roller.turn, coins)
+            })
+            .collect(),
+    }
+}
+
+// Each number of dice the player may roll on their own turn, and what it is expected to bring.
+pub fn get_roll_choices(players: &[Player], turn: usize) -> Vec<RollChoice> {
+    get_legal_roll_two_dice(&players[turn])
+        .into_iter()
+        .map(|roll_two_dice| {
+            let mut expected_coins = 0.0;
+            let mut activation_chance = 0.0;
+            for (total, chance) in get_roll_chances(roll_two_dice) {
+                let activated = get_activatable_cards(total, turn, players);
+                expected_coins += chance * get_activated_outcome(players, turn, turn, &activated);
+                if activated
+                    .iter()
+                    .any(|activated| activated.owner_turn == turn && is_open(&activated.card))
+                {
+                    activation_chance += chance;
+                }
+            }
+            RollChoice {
+                roll_two_dice,
+                expected_coins,
+                activation_chance,
+            }
+        })
+        .collect()
+}
+
+// Whether rolling two dice on their own turn is expected to pay the player the most. Rolls one
+// die when both are expected to pay the same.
+pub fn get_best_roll_two_dice(players: &[Player], turn: usize) -> bool {
+    get_best_roll_choice(&get_roll_choices(players, turn)).roll_two_dice
+}
+
+fn get_best_roll_choice(choices: &[RollChoice]) -> RollChoice {
+    // Reversed so that the earlier choice, one die, wins ties.
+    choices
+        .iter()
+        .rev()
+        .copied()
+        .max_by(|choice, other_choice| {
+            choice
+                .expected_coins
+                .total_cmp(&other_choice.expected_coins)
+        })
+        .expect("Player to be able to roll.")
+}
+
+// The coins the player expects to gain over a round.
+pub fn get_expected_income(players: &[Player], turn: usize) -> f64 {
+    get_income_forecast(players, turn).get_round_total()
+}
+
+// How much one more copy of the establishment raises the player's expected income per round,
+// given their income without it.
+pub fn get_income_gain(players: &[Player], turn: usize, income: f64, card_kind: CardKind) -> f64 {
+    let mut players = players.to_vec();
+    players[turn].cards.get_or_insert_mut(card_kind).count += 1;
+    get_expected_income(&players, turn) - income
+}
+
+// The coins one more copy of the establishment is expected to earn the player over a round,
+// assuming everyone rolls two dice when they can. Much quicker than get_income_gain, as it
+// ignores how the copy changes what the player's other establishments pay or cost.
+pub fn get_card_income(players: &[Player], turn: usize, card_kind: CardKind) -> f64 {
+    let owner = &players[turn];
+    let card = CardStack::new(card_kind, 1);
+    players
+        .iter()
+        .map(|roller| {
+            let roll_two_dice = get_legal_roll_two_dice(roller).len() > 1;
+            get_roll_chances(roll_two_dice)
+                .into_iter()
+                .filter(|(total, _)| {
+                    card.test_activation(*total, owner.turn == roller.turn)
+                        && card
+                            .get_required_landmark()
+                            .is_none_or(|landmark| owner.landmarks.contains(&landmark))
+                })
+                .map(|(_, chance)| chance * get_card_payout(players, turn, roller.turn, &card))
+                .sum::<f64>()
+        })
+        .sum()
+}
+
+// The coins the player expects to gain from a single roll by the roller.
+pub fn get_expected_roll_outcome(
+    players: &[Player],
+    turn: usize,
+    roller_turn: usize,
+    roll_two_dice: bool,
+) -> f64 {
+    get_roll_chances(roll_two_dice)
+        .into_iter()
+        .map(|(total, chance)| chance * get_roll_outcome(players, turn, roller_turn, total))
+        .sum()
+}
+
+// The coins the player gains, or loses when negative, when the roller rolls the total. A closed
+// establishment pays nothing, as activating it only reopens it.
+pub fn get_roll_outcome(players: &[Player], turn: usize, roller_turn: usize, total: u8) -> f64 {
+    let activated = get_activatable_cards(total, roller_turn, players);
+    get_activated_outcome(players, turn, roller_turn, &activated)
+}
+
+// Closed establishments reopen instead of paying out.
+#[cfg(feature = "millionaires-row")]
+fn is_open(card: &CardStack) -> bool {
+    !card.closed
+}
+
+#[cfg(not(feature = "millionaires-row"))]
+fn is_open(_card: &CardStack) -> bool {
+    true
+}
+
+fn get_activated_outcome(
+    players: &[Player],
+    turn: usize,
+    roller_turn: usize,
+    activated_cards: &[PlayerCardStack],
+) -> f64 {
+    let mut gained = 0.0;
+    let mut lost = 0.0;
+    for activated in activated_cards {
+        if !is_open(&activated.card) {
+            continue;
+        }
+        if activated.owner_turn == turn {
+            gained += get_card_payout(players, turn, roller_turn, &activated.card);
+        } else {
+            lost += get_card_cost(
+                players,
+                activated.owner_turn,
+                roller_turn,
+                turn,
+                &activated.card,
+            );
+        }
+    }
+    gained - f64::min(lost, f64::from(players[turn].coins))
+}
+
+// The coins the owner expects from a stack of establishments activated by the roller's roll.
+// Effects that move establishments or depend on later decisions count as nothing.
+pub fn get_card_payout(
+    players: &[Player],
+    owner_turn: usize,
+    roller_turn: usize,
+    card: &CardStack,
+) -> f64 {
+    let owner = &players[owner_turn];
+    let roller = &players[roller_turn];
+    let count = f64::from(card.count);
+    let bonus = get_shopping_mall_bonus(owner, card.get_icon());
+    let others = || players.iter().filter(|player| player.turn != owner_turn);
+    match get_card_definition(card.kind).effect {
+        CardEffect::Bank { coins } => count * f64::from(coins.saturating_add(bonus)),
+        CardEffect::TakeFromRoller { coins } => f64::min(
+            count * f64::from(coins.saturating_add(bonus)),
+            f64::from(roller.coins),
+        ),
+        CardEffect::Combo { icon, coins } => {
+            count * f64::from(coins) * f64::from(count_player_icons(owner, &[icon]))
+        }
+        CardEffect::TakeFromAll { coins } => {
+            count
+                * others()
+                    .map(|player| f64::from(u8::min(coins, player.coins)))
+                    .sum::<f64>()
+        }
+        CardEffect::TakeFromOne { coins } => {
+            count
+                * others()
+                    .map(|player| f64::from(u8::min(coins, player.coins)))
+                    .fold(0.0, f64::max)
+        }
+        // The Tuna Boat pays the total of two dice, which averages 7.
+        #[cfg(feature = "harbor")]
+        CardEffect::BankDiceRoll => count * 7.0,
+        #[cfg(feature = "harbor")]
+        CardEffect::TakeFromAllPerIcon { ref icons, coins } => {
+            count
+                * others()
+                    .map(|player| {
+                        let amount = count_player_icons(player, icons).saturating_mul(coins);
+                        f64::from(u8::min(amount, player.coins))
+                    })
+                    .sum::<f64>()
+        }
+        #[cfg(feature = "millionaires-row")]
+        CardEffect::ComboCard {
+            card: card_kind,
+            coins,
+        } => count * f64::from(coins) * f64::from(owner.cards.get_count(card_kind)),
+        #[cfg(feature = "millionaires-row")]
+        CardEffect::ComboAll { icon, coins } => {
+            let icon_count: f64 = players
+                .iter()
+                .map(|player| f64::from(count_player_icons(player, &[icon])))
+                .sum();
+            count * f64::from(coins) * icon_count
+        }
+        #[cfg(feature = "millionaires-row")]
+        CardEffect::TakeAllFromRoller => f64::from(roller.coins),
+        #[cfg(feature = "millionaires-row")]
+        CardEffect::PayBank { coins } => {
+            -f64::min(count * f64::from(coins), f64::from(owner.coins))
+        }
+        _ => 0.0,
+    }
+}
+
+// The coins the owner's activated establishments take from the victim.
+fn get_card_cost(
+    players: &[Player],
+    owner_turn: usize,
+    roller_turn: usize,
+    victim_turn: usize,
+    card: &CardStack,
+) -> f64 {
+    let count = f64::from(card.count);
+    match get_card_definition(card.kind).effect {
+        CardEffect::TakeFromRoller { coins } if victim_turn == roller_turn => {
+            let bonus = get_shopping_mall_bonus(&players[owner_turn], card.get_icon());
+            count * f64::from(coins.saturating_add(bonus))
+        }
+        CardEffect::TakeFromAll { coins } => count * f64::from(coins),
+        #[cfg(feature = "harbor")]
+        CardEffect::TakeFromAllPerIcon { ref icons, coins } => {
+            let icon_count = count_player_icons(&players[victim_turn], icons);
+            count * f64::from(icon_count.saturating_mul(coins))
+        }
+        #[cfg(feature = "millionaires-row")]
+        CardEffect::TakeAllFromRoller if victim_turn == roller_turn => {
+            f64::from(players[victim_turn].coins)
+        }
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{cards::CardKind, config::GameConfig, landmarks::LandmarkKind};
+
+    use super::{
+        get_best_roll_two_dice, get_card_income, get_expected_roll_outcome, get_income_forecast,
+        get_income_gain, get_roll_chances, get_roll_choices, get_roll_outcome,
+    };
+    use crate::game::test_helpers::get_players;
+
+    #[test]
+    fn test_roll_chances() {
+        let one_die = get_roll_chances(false);
+        assert_eq!(one_die.len(), 6);
+        assert_close(one_die.iter().map(|(_, chance)| chance).sum(), 1.0);
+
+        let two_dice = get_roll_chances(true);
+        assert_eq!(two_dice.first().map(|(total, _)| *total), Some(2));
+        assert_close(two_dice.iter().map(|(_, chance)| chance).sum(), 1.0);
+        assert_close(two_dice[5].1, 6.0 / 36.0);
+        assert_close(two_dice[0].1, 1.0 / 36.0);
+    }
+
+    #[test]
+    fn test_starting_forecast() {
+        let players = get_players(2, &GameConfig::default());
+        // The Wheat Field pays on a 1 and the Bakery on a 2 or 3.
+        let forecast = get_income_forecast(&players, 0);
+        assert_eq!(forecast.own_turn.len(), 1);
+        assert_close(forecast.get_best_own_turn().expected_coins, 0.5);
+        assert_eq!(forecast.other_turns.len(), 1);
+        assert_close(forecast.other_turns[0].1, 1.0 / 6.0);
+        assert_close(forecast.get_round_total(), 0.5 + 1.0 / 6.0);
+    }
+
+    #[test]
+    fn test_shopping_mall_and_combos() {
+        let mut players = get_players(2, &GameConfig::default());
+        players[0].landmarks.push(LandmarkKind::ShoppingMall);
+        assert_close(
+            get_expected_roll_outcome(&players, 0, 0, false),
+            1.0 / 6.0 + 2.0 * 2.0 / 6.0,
+        );
+
+        // The Cheese Factory pays 3 for each Ranch on a 7.
+        players[0].landmarks.push(LandmarkKind::TrainStation);
+        players[0].cards.set_count(CardKind::Ranch, 2);
+        players[0].cards.set_count(CardKind::CheeseFactory, 1);
+        assert_close(get_roll_outcome(&players, 0, 0, 7), 6.0);
+        let forecast = get_income_forecast(&players, 0);
+        assert_eq!(forecast.own_turn.len(), 2);
+        assert!(forecast.get_best_own_turn().roll_two_dice);
+    }
+
+    #[test]
+    fn test_roll_choices() {
+        let mut players = get_players(2, &GameConfig::default());
+        assert_eq!(get_roll_choices(&players, 0).len(), 1);
+        assert!(!get_best_roll_two_dice(&players, 0));
+
+        // Only the Wheat Field and Bakery pay, and two dice can't roll a 1.
+        players[0].landmarks.push(LandmarkKind::TrainStation);
+        let choices = get_roll_choices(&players, 0);
+        assert_eq!(choices.len(), 2);
+        assert_close(choices[0].activation_chance, 0.5);
+        assert_close(choices[1].activation_chance, 3.0 / 36.0);
+        assert_close(choices[1].expected_coins, 3.0 / 36.0);
+        assert!(!get_best_roll_two_dice(&players, 0));
+
+        // A Mine pays 5 on a 9, which is worth rolling two dice for.
+        players[0].cards.set_count(CardKind::Mine, 1);
+        let choices = get_roll_choices(&players, 0);
+        assert_close(choices[1].activation_chance, 7.0 / 36.0);
+        assert_close(choices[1].expected_coins, (3.0 + 4.0 * 5.0) / 36.0);
+        assert!(get_best_roll_two_dice(&players, 0));
+    }
+
+    #[test]
+    fn test_restaurants_cost_the_roller() {
+        let mut players = get_players(2, &GameConfig::default());
+        players[1].cards.set_count(CardKind::Cafe, 2);
+        // The Bakery pays 1 on a 3 but both Cafes take 1 each.
+        assert_close(get_roll_outcome(&players, 0, 0, 3), -1.0);
+        assert_close(get_roll_outcome(&players, 1, 0, 3), 2.0);
+
+        players[0].coins = 1;
+        assert_close(get_roll_outcome(&players, 0, 0, 3), 0.0);
+    }
+
+    #[test]
+    fn test_income_gain() {
+        let players = get_players(2, &GameConfig::default());
+        let income = get_income_forecast(&players, 0).get_round_total();
+        // Nothing above a 6 can be rolled without the Train Station.
+        assert_close(get_income_gain(&players, 0, income, CardKind::Mine), 0.0);
+        assert_close(
+            get_income_gain(&players, 0, income, CardKind::WheatField),
+            2.0 / 6.0,
+        );
+        assert_close(
+            get_card_income(&players, 0, CardKind::WheatField),
+            2.0 / 6.0,
+        );
+    }
+
+    fn assert_close(value: f64, expected: f64) {
+        assert!(
+            (value - expected).abs() < 1e-9,
+            "Expected {} to be {}.",
+            value,
+            expected
+        );
+    }
+}
@@ -1,43 +1,84 @@
-pub const CARD_KIND_COUNT: usize = 15;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+use super::{
+    landmarks::LandmarkKind,
+    registry::{get_card_definition, get_card_registry},
+};
+
+// Declared in the order cards are displayed, which is also the order decks iterate in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum CardKind {
     WheatField,
     Ranch,
-    Forest,
-    Mine,
-    AppleOrchard,
     Bakery,
-    ConvenienceStore,
-    CheeseFactory,
-    FurnitureFactory,
-    FruitAndVegetableMarket,
     Cafe,
-    FamilyRestaurant,
+    ConvenienceStore,
+    Forest,
     Stadium,
     TvStation,
     BusinessCenter,
+    CheeseFactory,
+    FurnitureFactory,
+    Mine,
+    FamilyRestaurant,
+    AppleOrchard,
+    FruitAndVegetableMarket,
+    #[cfg(feature = "harbor")]
+    SushiBar,
+    #[cfg(feature = "harbor")]
+    FlowerOrchard,
+    #[cfg(feature = "harbor")]
+    FlowerShop,
+    #[cfg(feature = "harbor")]
+    FoodWarehouse,
+    #[cfg(feature = "harbor")]
+    MackerelBoat,
+    #[cfg(feature = "harbor")]
+    TunaBoat,
+    #[cfg(feature = "harbor")]
+    PizzaJoint,
+    #[cfg(feature = "harbor")]
+    HamburgerStand,
+    #[cfg(feature = "harbor")]
+    Publisher,
+    #[cfg(feature = "harbor")]
+    TaxOffice,
+    #[cfg(feature = "millionaires-row")]
+    Vineyard,
+    #[cfg(feature = "millionaires-row")]
+    Winery,
+    #[cfg(feature = "millionaires-row")]
+    MovingCompany,
+    #[cfg(feature = "millionaires-row")]
+    LoanOffice,
+    #[cfg(feature = "millionaires-row")]
+    DemolitionCompany,
+    #[cfg(feature = "millionaires-row")]
+    Park,
+    #[cfg(feature = "millionaires-row")]
+    RenovationCompany,
+    #[cfg(feature = "millionaires-row")]
+    TechStartup,
+    #[cfg(feature = "millionaires-row")]
+    ExhibitHall,
+    #[cfg(feature = "millionaires-row")]
+    GeneralStore,
+    #[cfg(feature = "millionaires-row")]
+    FrenchRestaurant,
+    #[cfg(feature = "millionaires-row")]
+    MembersOnlyClub,
+    #[cfg(feature = "millionaires-row")]
+    CornField,
+    #[cfg(feature = "millionaires-row")]
+    SodaBottlingPlant,
 }
 
-pub const ALL_CARDS: [CardKind; CARD_KIND_COUNT] = [
-    CardKind::WheatField,
-    CardKind::Ranch,
-    CardKind::Bakery,
-    CardKind::Cafe,
-    CardKind::ConvenienceStore,
-    CardKind::Forest,
-    CardKind::Stadium,
-    CardKind::TvStation,
-    CardKind::BusinessCenter,
-    CardKind::CheeseFactory,
-    CardKind::FurnitureFactory,
-    CardKind::Mine,
-    CardKind::FamilyRestaurant,
-    CardKind::AppleOrchard,
-    CardKind::FruitAndVegetableMarket,
-];
-
-#[derive(PartialEq, Eq, PartialOrd, Ord)]
+// Every establishment in the loaded card definitions, in the order they are displayed.
+pub fn get_all_cards() -> impl Iterator<Item = CardKind> {
+    get_card_registry().get_card_kinds()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
 pub enum CardOrder {
     Restaurants = 0,
     SecondaryIndustry = 1,
@@ -45,7 +86,7 @@ pub enum CardOrder {
     MajorEstablishments = 3,
 }
 
-#[derive(PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
 pub enum CardIcon {
     Wheat,
     Cow,
@@ -55,102 +96,69 @@ pub enum CardIcon {
     Cup,
     Major,
     Fruit,
+    #[cfg(feature = "harbor")]
+    Boat,
+    #[cfg(feature = "harbor")]
+    Flower,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct CardStack {
     pub kind: CardKind,
     pub count: u8,
+    // Closed for renovation. The next time the establishments would activate they reopen instead.
+    #[cfg(feature = "millionaires-row")]
+    #[serde(default)]
+    pub closed: bool,
+    // Coins invested in the Tech Startup.
+    #[cfg(feature = "millionaires-row")]
+    #[serde(default)]
+    pub invested: u8,
 }
 
 pub fn get_card_title(card_kind: CardKind) -> &'static str {
-    match card_kind {
-        CardKind::WheatField => "Wheat Field",
-        CardKind::Ranch => "Ranch",
-        CardKind::Bakery => "Bakery",
-        CardKind::Cafe => "Cafe",
-        CardKind::ConvenienceStore => "Convenience Store",
-        CardKind::Forest => "Forest",
-        CardKind::Stadium => "Stadium",
-        CardKind::TvStation => "TV Station",
-        CardKind::BusinessCenter => "Business Center",
-        CardKind::CheeseFactory => "Cheese Factory",
-        CardKind::FurnitureFactory => "Furniture Factory",
-        CardKind::Mine => "Mine",
-        CardKind::FamilyRestaurant => "Family Restaurant",
-        CardKind::AppleOrchard => "Apple Orchard",
-        CardKind::FruitAndVegetableMarket => "Fruit and Vegetable Market",
-    }
+    &get_card_definition(card_kind).title
 }
 
 pub fn get_card_icon(card_kind: CardKind) -> CardIcon {
-    match card_kind {
-        CardKind::AppleOrchard | CardKind::WheatField => CardIcon::Wheat,
-        CardKind::Bakery | CardKind::ConvenienceStore => CardIcon::Bread,
-        CardKind::Ranch => CardIcon::Cow,
-        CardKind::Cafe | CardKind::FamilyRestaurant => CardIcon::Cup,
-        CardKind::Forest | CardKind::Mine => CardIcon::Gear,
-        CardKind::BusinessCenter | CardKind::Stadium | CardKind::TvStation => CardIcon::Major,
-        CardKind::CheeseFactory | CardKind::FurnitureFactory => CardIcon::Factory,
-        CardKind::FruitAndVegetableMarket => CardIcon::Fruit,
-    }
+    get_card_definition(card_kind).icon
 }
 
 impl CardStack {
+    pub fn new(kind: CardKind, count: u8) -> Self {
+        CardStack {
+            kind,
+            count,
+            #[cfg(feature = "millionaires-row")]
+            closed: false,
+            #[cfg(feature = "millionaires-row")]
+            invested: 0,
+        }
+    }
+
     pub fn test_activation(&self, roll: u8, is_current_turn: bool) -> bool {
-        (match self.kind {
-            CardKind::WheatField => roll == 1,
-            CardKind::Ranch => roll == 2,
-            CardKind::Bakery => roll == 2 || roll == 3,
-            CardKind::Cafe => roll == 3,
-            CardKind::ConvenienceStore => roll == 4,
-            CardKind::Forest => roll == 5,
-            CardKind::Stadium | CardKind::TvStation | CardKind::BusinessCenter => roll == 6,
-            CardKind::CheeseFactory => roll == 7,
-            CardKind::FurnitureFactory => roll == 8,
-            CardKind::Mine => roll == 9,
-            CardKind::FamilyRestaurant => roll == 9 || roll == 10,
-            CardKind::AppleOrchard => roll == 10,
-            CardKind::FruitAndVegetableMarket => roll == 11 || roll == 12,
-        } && match self.get_order() {
-            CardOrder::PrimaryIndustry => true,
-            CardOrder::MajorEstablishments | CardOrder::SecondaryIndustry => is_current_turn,
-            CardOrder::Restaurants => !is_current_turn,
-        })
+        get_card_definition(self.kind).activation.contains(&roll)
+            && match self.get_order() {
+                CardOrder::PrimaryIndustry => true,
+                CardOrder::MajorEstablishments | CardOrder::SecondaryIndustry => is_current_turn,
+                CardOrder::Restaurants => !is_current_turn,
+            }
     }
 
     pub fn get_cost(&self) -> u8 {
-        match self.kind {
-            CardKind::WheatField | CardKind::Ranch | CardKind::Bakery => 1,
-            CardKind::Cafe | CardKind::ConvenienceStore | CardKind::FruitAndVegetableMarket => 2,
-            CardKind::Forest
-            | CardKind::FurnitureFactory
-            | CardKind::FamilyRestaurant
-            | CardKind::AppleOrchard => 3,
-            CardKind::CheeseFactory => 5,
-            CardKind::Mine | CardKind::Stadium => 6,
-            CardKind::TvStation => 7,
-            CardKind::BusinessCenter => 8,
+        get_card_definition(self.kind).cost
+    }
+
+    // The cost as printed on the card, where a loan shows as a negative cost.
+    pub fn get_cost_description(&self) -> String {
+        match get_card_definition(self.kind).loan {
+            0 => self.get_cost().to_string(),
+            loan => format!("-{}", loan),
         }
     }
 
     pub fn get_order(&self) -> CardOrder {
-        match self.kind {
-            CardKind::WheatField
-            | CardKind::Ranch
-            | CardKind::Forest
-            | CardKind::Mine
-            | CardKind::AppleOrchard => CardOrder::PrimaryIndustry,
-            CardKind::Bakery
-            | CardKind::ConvenienceStore
-            | CardKind::CheeseFactory
-            | CardKind::FurnitureFactory
-            | CardKind::FruitAndVegetableMarket => CardOrder::SecondaryIndustry,
-            CardKind::Cafe | CardKind::FamilyRestaurant => CardOrder::Restaurants,
-            CardKind::Stadium | CardKind::TvStation | CardKind::BusinessCenter => {
-                CardOrder::MajorEstablishments
-            }
-        }
+        get_card_definition(self.kind).order
     }
 
     pub fn get_order_title(&self) -> &'static str {
@@ -162,6 +170,11 @@ impl CardStack {
         }
     }
 
+    // The landmark the owner must have built for the card to activate, if any.
+    pub fn get_required_landmark(&self) -> Option<LandmarkKind> {
+        get_card_definition(self.kind).requires.clone()
+    }
+
     pub fn get_icon(&self) -> CardIcon {
         get_card_icon(self.kind)
     }
@@ -176,6 +189,10 @@ impl CardStack {
             CardIcon::Major => "Major",
             CardIcon::Factory => "Factory",
             CardIcon::Fruit => "Fruit",
+            #[cfg(feature = "harbor")]
+            CardIcon::Boat => "Boat",
+            #[cfg(feature = "harbor")]
+            CardIcon::Flower => "Flower",
         }
     }
 
@@ -184,42 +201,10 @@ impl CardStack {
     }
 
     pub fn get_description(&self) -> &'static str {
-        match self.kind {
-            CardKind::WheatField | CardKind::Ranch | CardKind::Forest => {
-                "Get 1 coin from the bank, on anyone's turn."
-            }
-            CardKind::Bakery => "Get 1 coin from the bank, on your turn only.",
-            CardKind::Cafe => "Get 1 coin from the player who rolled the dice.",
-            CardKind::ConvenienceStore => "Get 3 coins from the bank, on your turn only.",
-            CardKind::Stadium => "Get 2 coins from all players, on your turn only.",
-            CardKind::TvStation => "If this is your turn, take 5 coins from any one player.",
-            CardKind::BusinessCenter => {
-                "If this is your turn, trade one non-Major establishment with another player."
-            }
-            CardKind::CheeseFactory => "If this is your turn, get 3 coins from the bank for each Cow establishment that you own.",
-            CardKind::FurnitureFactory => "If this is your turn, get 3 coins from the bank for each Gear establishment that you own.",
-            CardKind::Mine => "Get 5 coins from the bank, on anyone's turn.",
-            CardKind::FamilyRestaurant => "Get 2 coins from the player who rolled the dice.",
-            CardKind::AppleOrchard => "Get 3 coins from the bank, on anyone's turn.",
-            CardKind::FruitAndVegetableMarket => "If this is your turn, get 2 coins from the bank for each Wheat establishment that you own.",
-        }
+        &get_card_definition(self.kind).description
     }
 
-    pub fn get_activation_description(&self) -> &'static str {
-        match self.kind {
-            CardKind::WheatField => "1",
-            CardKind::Ranch => "2",
-            CardKind::Bakery => "2-3",
-            CardKind::Cafe => "3",
-            CardKind::ConvenienceStore => "4",
-            CardKind::Forest => "5",
-            CardKind::Stadium | CardKind::TvStation | CardKind::BusinessCenter => "6",
-            CardKind::CheeseFactory => "7",
-            CardKind::FurnitureFactory => "8",
-            CardKind::Mine => "9",
-            CardKind::FamilyRestaurant => "9-10",
-            CardKind::AppleOrchard => "10",
-            CardKind::FruitAndVegetableMarket => "11-12",
-        }
+    pub fn get_activation_description(&self) -> String {
+        get_card_definition(self.kind).get_activation_description()
     }
 }
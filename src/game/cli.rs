@@ -0,0 +1,30 @@
+use std::str::FromStr;
+use tabled::{
+    builder::Builder,
+    object::{Columns, Segment},
+    Alignment, Header, MaxWidth, Modify, Style,
+};
+
+// Parses the number given after a command-line argument.
+pub fn parse_number<T: FromStr>(arg: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("Expected a number after {}.", arg))?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid number \"{}\" for {}.", value, arg))
+}
+
+// Prints the rows under the column names, headed by a title and wrapped to fit the terminal.
+pub fn print_table(header: &str, columns: Vec<&str>, data: Vec<Vec<String>>) {
+    let table = Builder::from(data)
+        .set_columns(columns)
+        .build()
+        .with(Header(header))
+        .with(Style::modern())
+        .with(
+            Modify::new(Segment::all())
+                .with(Alignment::left())
+                .with(Alignment::top()),
+        )
+        .with(Modify::new(Columns::new(0..)).with(MaxWidth::wrapping(70)));
+    println!("{}", table);
+}
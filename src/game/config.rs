@@ -0,0 +1,306 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs, io,
+};
+
+use super::{
+    cards::{get_card_icon, CardIcon, CardKind},
+    landmarks::{
+        get_landmark_cost, get_landmark_expansion, is_landmark_built_at_start, LandmarkKind,
+        ALL_LANDMARKS,
+    },
+    registry::{get_card_definition, get_card_registry},
+};
+
+// A published expansion that adds establishments and landmarks to the base game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Expansion {
+    Harbor,
+    MillionairesRow,
+}
+
+impl Expansion {
+    // The cargo feature that compiles in the expansion's establishments and landmarks.
+    pub fn get_feature_name(self) -> &'static str {
+        match self {
+            Expansion::Harbor => "harbor",
+            Expansion::MillionairesRow => "millionaires-row",
+        }
+    }
+
+    // Whether this build was compiled with the expansion's feature.
+    pub fn is_compiled_in(self) -> bool {
+        match self {
+            Expansion::Harbor => cfg!(feature = "harbor"),
+            Expansion::MillionairesRow => cfg!(feature = "millionaires-row"),
+        }
+    }
+}
+
+// How the establishments for sale are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SupplyMode {
+    // Every establishment can be bought at any time.
+    Full,
+    // Establishments are drawn from a shuffled deck until a number of distinct piles are face
+    // up, and only those can be bought.
+    Marketplace,
+}
+
+// Everything about the rules that can change between games without recompiling. The defaults
+// follow the published rules, and any setting left out of a config file keeps its default.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GameConfig {
+    // The expansions played with on top of the base game.
+    pub expansions: BTreeSet<Expansion>,
+    pub starting_coins: u8,
+    // The establishments every player starts with.
+    pub starting_cards: BTreeMap<CardKind, u8>,
+    // How many of each establishment can be bought. Establishments left out keep the count from
+    // their card definition.
+    pub supply: BTreeMap<CardKind, u8>,
+    // Landmarks left out keep the published cost.
+    pub landmark_costs: BTreeMap<LandmarkKind, u8>,
+    pub max_player_count: usize,
+    // How many landmarks a player must build to win, or None for all of the landmarks in play.
+    pub landmarks_to_win: Option<usize>,
+    // Whether each player may own at most one of each Major establishment.
+    pub limit_major_establishments: bool,
+    pub supply_mode: SupplyMode,
+    // How many distinct piles are face up in the marketplace.
+    pub marketplace_piles: usize,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            expansions: BTreeSet::new(),
+            starting_coins: 3,
+            starting_cards: BTreeMap::from([(CardKind::WheatField, 1), (CardKind::Bakery, 1)]),
+            supply: BTreeMap::new(),
+            landmark_costs: BTreeMap::new(),
+            max_player_count: 4,
+            landmarks_to_win: None,
+            limit_major_establishments: true,
+            supply_mode: SupplyMode::Full,
+            marketplace_piles: 10,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(error) => write!(f, "{}", error),
+            ConfigError::Toml(error) => write!(f, "Invalid config file: {}", error),
+            ConfigError::Invalid(message) => write!(f, "Invalid config: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<io::Error> for ConfigError {
+    fn from(error: io::Error) -> Self {
+        ConfigError::Io(error)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(error: toml::de::Error) -> Self {
+        ConfigError::Toml(error)
+    }
+}
+
+impl GameConfig {
+    pub fn from_toml(toml: &str) -> Result<Self, ConfigError> {
+        let config: GameConfig = toml::from_str(toml)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    // Checks for settings that would make the game unplayable.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.max_player_count < 2 {
+            return Err(ConfigError::Invalid(String::from(
+                "max_player_count must be at least 2.",
+            )));
+        }
+        if let Some(expansion) = self
+            .expansions
+            .iter()
+            .find(|expansion| !expansion.is_compiled_in())
+        {
+            return Err(ConfigError::Invalid(format!(
+                "the {:?} expansion needs a build with the {} feature.",
+                expansion,
+                expansion.get_feature_name()
+            )));
+        }
+        if let Some(card_kind) = self
+            .starting_cards
+            .keys()
+            .chain(self.supply.keys())
+            .find(|card_kind| !get_card_registry().contains(**card_kind))
+        {
+            return Err(ConfigError::Invalid(format!(
+                "{:?} is not one of the establishments being played with.",
+                card_kind
+            )));
+        }
+        if self.marketplace_piles == 0 {
+            return Err(ConfigError::Invalid(String::from(
+                "marketplace_piles must be at least 1.",
+            )));
+        }
+        if let Some(landmarks_to_win) = self.landmarks_to_win {
+            let landmark_count = self.get_landmarks().len();
+            if landmarks_to_win == 0 || landmarks_to_win > landmark_count {
+                return Err(ConfigError::Invalid(format!(
+                    "landmarks_to_win must be between 1 and {}.",
+                    landmark_count
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    // Whether content from the expansion is in play. None stands for the base game.
+    pub fn has_expansion(&self, expansion: Option<Expansion>) -> bool {
+        expansion.is_none_or(|expansion| self.expansions.contains(&expansion))
+    }
+
+    pub fn is_card_in_play(&self, card_kind: CardKind) -> bool {
+        self.has_expansion(get_card_definition(card_kind).expansion)
+    }
+
+    pub fn is_landmark_in_play(&self, landmark_kind: &LandmarkKind) -> bool {
+        self.has_expansion(get_landmark_expansion(landmark_kind))
+    }
+
+    // Every landmark in play, in the order they are built.
+    pub fn get_landmarks(&self) -> Vec<LandmarkKind> {
+        ALL_LANDMARKS
+            .iter()
+            .filter(|landmark| self.is_landmark_in_play(landmark))
+            .cloned()
+            .collect()
+    }
+
+    // The landmarks every player has built before the first turn.
+    pub fn get_starting_landmarks(&self) -> Vec<LandmarkKind> {
+        self.get_landmarks()
+            .into_iter()
+            .filter(is_landmark_built_at_start)
+            .collect()
+    }
+
+    pub fn get_starting_count(&self, card_kind: CardKind) -> u8 {
+        self.starting_cards
+            .get(&card_kind)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn get_supply_count(&self, card_kind: CardKind) -> u8 {
+        if !self.is_card_in_play(card_kind) {
+            return 0;
+        }
+        self.supply
+            .get(&card_kind)
+            .copied()
+            .unwrap_or(get_card_definition(card_kind).supply)
+    }
+
+    pub fn get_landmark_cost(&self, landmark_kind: &LandmarkKind) -> u8 {
+        self.landmark_costs
+            .get(landmark_kind)
+            .copied()
+            .unwrap_or_else(|| get_landmark_cost(landmark_kind))
+    }
+
+    // The most copies of the card a single player may own, if there is a limit.
+    pub fn get_card_limit(&self, card_kind: CardKind) -> Option<u8> {
+        if self.limit_major_establishments && get_card_icon(card_kind) == CardIcon::Major {
+            Some(1)
+        } else {
+            None
+        }
+    }
+}
+
+pub fn load_config(path: &str) -> Result<GameConfig, ConfigError> {
+    GameConfig::from_toml(&fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{cards::CardKind, landmarks::LandmarkKind};
+
+    use super::{ConfigError, GameConfig};
+
+    #[test]
+    fn test_empty_config_is_default() {
+        assert_eq!(
+            GameConfig::from_toml("").expect("Config to be valid."),
+            GameConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_partial_config() {
+        let config = GameConfig::from_toml(
+            r#"
+            starting_coins = 0
+            landmarks_to_win = 2
+
+            [supply]
+            Stadium = 1
+
+            [landmark_costs]
+            RadioTower = 16
+            "#,
+        )
+        .expect("Config to be valid.");
+        assert_eq!(config.starting_coins, 0);
+        assert_eq!(config.get_starting_count(CardKind::WheatField), 1);
+        assert_eq!(config.get_supply_count(CardKind::Stadium), 1);
+        assert_eq!(config.get_supply_count(CardKind::Mine), 6);
+        assert_eq!(config.get_landmark_cost(&LandmarkKind::RadioTower), 16);
+        assert_eq!(config.get_landmark_cost(&LandmarkKind::TrainStation), 4);
+        assert_eq!(config.landmarks_to_win, Some(2));
+    }
+
+    #[test]
+    #[cfg(not(feature = "harbor"))]
+    fn test_expansion_not_compiled_in() {
+        let config = GameConfig::default();
+        assert_eq!(config.get_landmarks().len(), 4);
+        assert!(config.get_starting_landmarks().is_empty());
+        assert!(matches!(
+            GameConfig::from_toml("expansions = [\"Harbor\"]"),
+            Err(ConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(matches!(
+            GameConfig::from_toml("max_player_count = 1"),
+            Err(ConfigError::Invalid(_))
+        ));
+        assert!(matches!(
+            GameConfig::from_toml("[supply]\nCastle = 2"),
+            Err(ConfigError::Toml(_))
+        ));
+    }
+}
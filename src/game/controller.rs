@@ -1,13 +1,26 @@
 use rand::Rng;
-use std::cmp::Ordering;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, fmt};
 
+#[cfg(feature = "millionaires-row")]
+use super::cards::CardOrder;
 use super::{
-    cards::{get_card_icon, CardIcon, CardKind, CardStack, ALL_CARDS, CARD_KIND_COUNT},
-    landmarks::{get_landmark_cost, LandmarkKind, ALL_LANDMARKS},
+    cards::{get_all_cards, get_card_icon, get_card_title, CardIcon, CardKind, CardStack},
+    config::GameConfig,
+    deck::Deck,
+    landmarks::{get_landmark_title, is_landmark_built_at_start, LandmarkKind},
     player::Player,
+    registry::{get_card_definition, CardCondition},
 };
 
-pub type Deck = [CardStack; CARD_KIND_COUNT];
+// What a player does at the end of their turn.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Purchase {
+    Pass,
+    Card(CardKind),
+    Landmark(LandmarkKind),
+}
 
 // A CardStack owned by the specified player.
 #[derive(Clone)]
@@ -16,18 +29,107 @@ pub struct PlayerCardStack {
     pub card: CardStack,
 }
 
-pub fn award_coins(players: &mut Vec<Player>, player_turn: usize, amount: u8) -> u8 {
-    let player = players
-        .get_mut(player_turn)
-        .expect("Player to be in bounds.");
+// An illegal move or an inconsistent game state, reported instead of panicking.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameError {
+    PlayerNotFound(usize),
+    CannotTargetSelf(usize),
+    CardNotFound(CardKind),
+    NotEnoughCards(CardKind),
+    NotEnoughCoins {
+        cost: u8,
+        coins: u8,
+    },
+    CardLimitReached(CardKind),
+    MajorEstablishmentTrade(CardKind),
+    DuplicateLandmark(LandmarkKind),
+    LandmarkNotInPlay(LandmarkKind),
+    #[cfg(feature = "millionaires-row")]
+    CannotDemolish(LandmarkKind),
+    #[cfg(feature = "millionaires-row")]
+    CannotRenovate(CardKind),
+    #[cfg(feature = "millionaires-row")]
+    CannotExhibit(CardKind),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameError::PlayerNotFound(turn) => write!(f, "There is no player {}.", turn + 1),
+            GameError::CannotTargetSelf(turn) => {
+                write!(f, "Player {} cannot target themselves.", turn + 1)
+            }
+            GameError::CardNotFound(card_kind) => {
+                write!(f, "The {} is not in the game.", get_card_title(*card_kind))
+            }
+            GameError::NotEnoughCards(card_kind) => {
+                write!(
+                    f,
+                    "There are not enough {} cards.",
+                    get_card_title(*card_kind)
+                )
+            }
+            GameError::NotEnoughCoins { cost, coins } => write!(
+                f,
+                "The cost is {} coins but only {} are available.",
+                cost, coins
+            ),
+            GameError::CardLimitReached(card_kind) => write!(
+                f,
+                "Each player may only own one {}.",
+                get_card_title(*card_kind)
+            ),
+            GameError::MajorEstablishmentTrade(card_kind) => write!(
+                f,
+                "The {} is a Major establishment and cannot be traded.",
+                get_card_title(*card_kind)
+            ),
+            GameError::DuplicateLandmark(landmark_kind) => write!(
+                f,
+                "The {} has already been built.",
+                get_landmark_title(landmark_kind)
+            ),
+            GameError::LandmarkNotInPlay(landmark_kind) => write!(
+                f,
+                "The {} is not part of this game.",
+                get_landmark_title(landmark_kind)
+            ),
+            #[cfg(feature = "millionaires-row")]
+            GameError::CannotDemolish(landmark_kind) => write!(
+                f,
+                "The {} cannot be demolished.",
+                get_landmark_title(landmark_kind)
+            ),
+            #[cfg(feature = "millionaires-row")]
+            GameError::CannotRenovate(card_kind) => write!(
+                f,
+                "The {} cannot be closed for renovation.",
+                get_card_title(*card_kind)
+            ),
+            #[cfg(feature = "millionaires-row")]
+            GameError::CannotExhibit(card_kind) => write!(
+                f,
+                "The {} cannot be activated by the Exhibit Hall.",
+                get_card_title(*card_kind)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GameError {}
+
+pub fn award_coins(
+    players: &mut [Player],
+    player_turn: usize,
+    amount: u8,
+) -> Result<u8, GameError> {
+    let player = get_player_mut(players, player_turn)?;
     player.coins = u8::saturating_add(player.coins, amount);
-    amount
+    Ok(amount)
 }
 
-pub fn take_coins(players: &mut Vec<Player>, player_turn: usize, amount: u8) -> u8 {
-    let player = players
-        .get_mut(player_turn)
-        .expect("Player to be in bounds.");
+pub fn take_coins(players: &mut [Player], player_turn: usize, amount: u8) -> Result<u8, GameError> {
+    let player = get_player_mut(players, player_turn)?;
 
     let available_amount = if u8::checked_sub(player.coins, amount).is_none() {
         player.coins
@@ -36,153 +138,620 @@ pub fn take_coins(players: &mut Vec<Player>, player_turn: usize, amount: u8) ->
     };
 
     player.coins = u8::saturating_sub(player.coins, available_amount);
-    available_amount
+    Ok(available_amount)
 }
 
 pub fn steal_coins(
-    players: &mut Vec<Player>,
+    players: &mut [Player],
     from_player_turn: usize,
     to_player_turn: usize,
     amount: u8,
-) -> u8 {
-    let available_amount = take_coins(players, from_player_turn, amount);
-    award_coins(players, to_player_turn, available_amount);
-    available_amount
+) -> Result<u8, GameError> {
+    if from_player_turn == to_player_turn {
+        return Err(GameError::CannotTargetSelf(to_player_turn));
+    }
+    get_player(players, to_player_turn)?;
+    let available_amount = take_coins(players, from_player_turn, amount)?;
+    award_coins(players, to_player_turn, available_amount)?;
+    Ok(available_amount)
 }
 
 pub fn award_coins_combo(
-    players: &mut Vec<Player>,
+    players: &mut [Player],
     player_turn: usize,
     card_icon: CardIcon,
     amount: u8,
-) -> u8 {
-    let player = players.get(player_turn).expect("Player to be in boundsyy.");
+) -> Result<u8, GameError> {
+    let player = get_player(players, player_turn)?;
     let card_count: u8 = player
         .cards
         .iter()
         .filter(|card| card.get_icon() == card_icon)
         .map(|card| card.count)
         .sum();
-    let total_amount = u8::saturating_mul(
-        card_count
-            .try_into()
-            .expect("Number of cards to not overflow u8."),
-        amount,
-    );
-    award_coins(players, player_turn, total_amount);
-    total_amount
+    let total_amount = u8::saturating_mul(card_count, amount);
+    award_coins(players, player_turn, total_amount)
 }
 
+// Gets coins from the bank for each copy of the card the player owns.
+#[cfg(feature = "millionaires-row")]
+pub fn award_coins_combo_card(
+    players: &mut [Player],
+    player_turn: usize,
+    card_kind: CardKind,
+    amount: u8,
+) -> Result<u8, GameError> {
+    let card_count = get_player(players, player_turn)?.cards.get_count(card_kind);
+    let total_amount = u8::saturating_mul(card_count, amount);
+    award_coins(players, player_turn, total_amount)
+}
+
+// Gets coins from the bank for each establishment with the icon owned by any player.
+#[cfg(feature = "millionaires-row")]
+pub fn award_coins_combo_all(
+    players: &mut [Player],
+    player_turn: usize,
+    card_icon: CardIcon,
+    amount: u8,
+) -> Result<u8, GameError> {
+    let card_count = players
+        .iter()
+        .map(|player| count_player_icons(player, &[card_icon]))
+        .fold(0, u8::saturating_add);
+    let total_amount = u8::saturating_mul(card_count, amount);
+    award_coins(players, player_turn, total_amount)
+}
+
+// Shares every player's coins evenly, rounding up with coins from the bank. Returns how many
+// coins each player gained, or lost when negative.
+#[cfg(feature = "millionaires-row")]
+pub fn share_coins_evenly(players: &mut [Player]) -> Vec<(usize, i16)> {
+    let total: u16 = players.iter().map(|player| u16::from(player.coins)).sum();
+    let player_count = players.len() as u16;
+    let share = u8::try_from(total.div_ceil(player_count)).unwrap_or(u8::MAX);
+    players
+        .iter_mut()
+        .map(|player| {
+            let change = i16::from(share) - i16::from(player.coins);
+            player.coins = share;
+            (player.turn, change)
+        })
+        .collect()
+}
+
+// The number of establishments the player owns with any of the icons.
+pub fn count_player_icons(player: &Player, card_icons: &[CardIcon]) -> u8 {
+    player
+        .cards
+        .iter()
+        .filter(|card| card_icons.contains(&card.get_icon()))
+        .map(|card| card.count)
+        .sum()
+}
+
+// The extra coins each of the player's establishments with the icon earns from the Shopping Mall.
+pub fn get_shopping_mall_bonus(player: &Player, card_icon: CardIcon) -> u8 {
+    if matches!(card_icon, CardIcon::Bread | CardIcon::Cup)
+        && player.landmarks.contains(&LandmarkKind::ShoppingMall)
+    {
+        1
+    } else {
+        0
+    }
+}
+
+// Returns the turn of each player stolen from along with the amount taken from them.
 pub fn steal_coins_from_all(
-    players: &mut Vec<Player>,
+    players: &mut [Player],
     player_turn: usize,
     player_count: usize,
     amount: u8,
-) -> u8 {
-    let mut total_coins_stolen: u8 = 0;
-    for i in (0..player_count).filter(|i| i.clone() != player_turn) {
-        total_coins_stolen += steal_coins(players, i, player_turn, amount);
-    }
-    total_coins_stolen
+) -> Result<Vec<(usize, u8)>, GameError> {
+    (0..player_count)
+        .filter(|i| *i != player_turn)
+        .map(|i| Ok((i, steal_coins(players, i, player_turn, amount)?)))
+        .collect()
+}
+
+// Takes coins from every other player for each of their establishments with any of the icons.
+// Returns the turn of each player stolen from along with the amount taken from them.
+#[cfg(feature = "harbor")]
+pub fn steal_coins_per_icon_from_all(
+    players: &mut [Player],
+    player_turn: usize,
+    player_count: usize,
+    card_icons: &[CardIcon],
+    amount: u8,
+) -> Result<Vec<(usize, u8)>, GameError> {
+    (0..player_count)
+        .filter(|i| *i != player_turn)
+        .map(|i| {
+            let card_count = count_player_icons(get_player(players, i)?, card_icons);
+            let total_amount = u8::saturating_mul(card_count, amount);
+            Ok((i, steal_coins(players, i, player_turn, total_amount)?))
+        })
+        .collect()
+}
+
+// Takes half of the coins, rounded down, from every other player with at least the threshold.
+// Returns the turn of each player stolen from along with the amount taken from them.
+#[cfg(feature = "harbor")]
+pub fn steal_half_coins_from_rich(
+    players: &mut [Player],
+    player_turn: usize,
+    player_count: usize,
+    threshold: u8,
+) -> Result<Vec<(usize, u8)>, GameError> {
+    (0..player_count)
+        .filter(|i| *i != player_turn)
+        .filter_map(|i| match get_player(players, i) {
+            Ok(player) if player.coins < threshold => None,
+            Ok(player) => Some(Ok((i, player.coins / 2))),
+            Err(error) => Some(Err(error)),
+        })
+        .collect::<Result<Vec<(usize, u8)>, GameError>>()?
+        .into_iter()
+        .map(|(i, amount)| Ok((i, steal_coins(players, i, player_turn, amount)?)))
+        .collect()
 }
 
 pub fn trade_establishments(
-    players: &mut Vec<Player>,
+    players: &mut [Player],
     player_turn: usize,
     other_player_turn: usize,
     player_card_kind: CardKind,
     other_player_card_kind: CardKind,
-) {
-    if get_card_icon(player_card_kind) == CardIcon::Major
-        || get_card_icon(other_player_card_kind) == CardIcon::Major
-    {
-        panic!("Should not be able to trade Major establishments.");
+) -> Result<(), GameError> {
+    if player_turn == other_player_turn {
+        return Err(GameError::CannotTargetSelf(player_turn));
+    }
+    for card_kind in [player_card_kind, other_player_card_kind] {
+        if get_card_icon(card_kind) == CardIcon::Major {
+            return Err(GameError::MajorEstablishmentTrade(card_kind));
+        }
+    }
+
+    // Check both sides before changing anything so that a rejected trade has no effect.
+    for (turn, card_kind) in [
+        (player_turn, player_card_kind),
+        (other_player_turn, other_player_card_kind),
+    ] {
+        if get_player_card(get_player(players, turn)?, card_kind)?.count == 0 {
+            return Err(GameError::NotEnoughCards(card_kind));
+        }
+    }
+
+    remove_player_card(players, player_turn, player_card_kind, 1)?;
+    remove_player_card(players, other_player_turn, other_player_card_kind, 1)?;
+    add_player_card(players, player_turn, other_player_card_kind, 1)?;
+    add_player_card(players, other_player_turn, player_card_kind, 1)
+}
+
+// Gives one of the player's non-Major establishments to another player.
+#[cfg(feature = "millionaires-row")]
+pub fn give_establishment(
+    players: &mut [Player],
+    player_turn: usize,
+    other_player_turn: usize,
+    card_kind: CardKind,
+) -> Result<(), GameError> {
+    if player_turn == other_player_turn {
+        return Err(GameError::CannotTargetSelf(player_turn));
+    }
+    if get_card_icon(card_kind) == CardIcon::Major {
+        return Err(GameError::MajorEstablishmentTrade(card_kind));
+    }
+    get_player(players, other_player_turn)?;
+    remove_player_card(players, player_turn, card_kind, 1)?;
+    add_player_card(players, other_player_turn, card_kind, 1)
+}
+
+// Turns one of the player's landmarks back face down. Landmarks every player starts with can't
+// be demolished.
+#[cfg(feature = "millionaires-row")]
+pub fn demolish_landmark(
+    players: &mut [Player],
+    player_turn: usize,
+    landmark_kind: &LandmarkKind,
+) -> Result<(), GameError> {
+    let player = get_player_mut(players, player_turn)?;
+    if is_landmark_built_at_start(landmark_kind) {
+        return Err(GameError::CannotDemolish(landmark_kind.clone()));
     }
+    let index = player
+        .landmarks
+        .iter()
+        .position(|landmark| landmark == landmark_kind)
+        .ok_or_else(|| GameError::CannotDemolish(landmark_kind.clone()))?;
+    player.landmarks.remove(index);
+    Ok(())
+}
 
-    remove_player_card(players, player_turn, player_card_kind, 1);
-    remove_player_card(players, other_player_turn, other_player_card_kind, 1);
-    add_player_card(players, player_turn, other_player_card_kind, 1);
-    add_player_card(players, other_player_turn, player_card_kind, 1);
+// Closes every player's establishments of a non-Major kind for renovation. Returns the turn of
+// each player whose establishments closed along with how many closed.
+#[cfg(feature = "millionaires-row")]
+pub fn renovate_establishments(
+    players: &mut [Player],
+    card_kind: CardKind,
+) -> Result<Vec<(usize, u8)>, GameError> {
+    if get_card_icon(card_kind) == CardIcon::Major {
+        return Err(GameError::CannotRenovate(card_kind));
+    }
+    let mut closed = Vec::new();
+    for player in players.iter_mut() {
+        let player_turn = player.turn;
+        let card = get_player_card_mut(player, card_kind)?;
+        if card.count > 0 && !card.closed {
+            card.closed = true;
+            closed.push((player_turn, card.count));
+        }
+    }
+    Ok(closed)
+}
+
+#[cfg(feature = "millionaires-row")]
+pub fn set_establishments_closed(
+    players: &mut [Player],
+    player_turn: usize,
+    card_kind: CardKind,
+    closed: bool,
+) -> Result<(), GameError> {
+    get_player_card_mut(get_player_mut(players, player_turn)?, card_kind)?.closed = closed;
+    Ok(())
+}
+
+#[cfg(feature = "millionaires-row")]
+pub fn is_establishment_closed(
+    players: &[Player],
+    player_turn: usize,
+    card_kind: CardKind,
+) -> Result<bool, GameError> {
+    Ok(get_player_card(get_player(players, player_turn)?, card_kind)?.closed)
+}
+
+// Moves one coin from the player onto their Tech Startup.
+#[cfg(feature = "millionaires-row")]
+pub fn invest_coin(players: &mut [Player], player_turn: usize) -> Result<(), GameError> {
+    let player = get_player_mut(players, player_turn)?;
+    check_cost(player, 1)?;
+    let card = get_player_card_mut(player, CardKind::TechStartup)?;
+    if card.count == 0 {
+        return Err(GameError::NotEnoughCards(CardKind::TechStartup));
+    }
+    card.invested = u8::saturating_add(card.invested, 1);
+    player.coins -= 1;
+    Ok(())
+}
+
+#[cfg(feature = "millionaires-row")]
+pub fn get_invested_coins(players: &[Player], player_turn: usize) -> Result<u8, GameError> {
+    Ok(get_player_card(get_player(players, player_turn)?, CardKind::TechStartup)?.invested)
+}
+
+// Returns one of the player's establishments to the supply.
+#[cfg(feature = "millionaires-row")]
+pub fn return_establishment(
+    players: &mut [Player],
+    player_turn: usize,
+    card_deck: &mut Deck,
+    card_kind: CardKind,
+) -> Result<(), GameError> {
+    remove_player_card(players, player_turn, card_kind, 1)?;
+    let card = card_deck.get_or_insert_mut(card_kind);
+    card.count = u8::saturating_add(card.count, 1);
+    Ok(())
+}
+
+// The number of landmarks the player has built, not counting the ones every player starts with.
+pub fn count_built_landmarks(player: &Player) -> usize {
+    player
+        .landmarks
+        .iter()
+        .filter(|landmark| !is_landmark_built_at_start(landmark))
+        .count()
+}
+
+pub fn can_afford(player: &Player, cost: u8) -> bool {
+    player.coins >= cost
+}
+
+// Checks that the player could make the purchase right now, without making it.
+pub fn check_purchase(
+    player: &Player,
+    card_deck: &Deck,
+    config: &GameConfig,
+    purchase: &Purchase,
+) -> Result<(), GameError> {
+    match purchase {
+        Purchase::Pass => Ok(()),
+        Purchase::Card(card_kind) => check_card_purchase(player, card_deck, config, *card_kind),
+        Purchase::Landmark(landmark_kind) => check_landmark_purchase(player, config, landmark_kind),
+    }
+}
+
+pub fn is_legal_purchase(
+    player: &Player,
+    card_deck: &Deck,
+    config: &GameConfig,
+    purchase: &Purchase,
+) -> bool {
+    check_purchase(player, card_deck, config, purchase).is_ok()
+}
+
+// The legal actions at each decision point of a turn. Strategies and the UI choose from these
+// rather than working out the rules themselves.
+
+// Whether the player may roll one die (false) and two dice (true).
+pub fn get_legal_roll_two_dice(player: &Player) -> Vec<bool> {
+    if player.landmarks.contains(&LandmarkKind::TrainStation) {
+        vec![false, true]
+    } else {
+        vec![false]
+    }
+}
+
+// Whether the player may add 2 to the roll total with the Harbor.
+#[cfg(feature = "harbor")]
+pub fn get_legal_add_two(player: &Player, roll_total: u8) -> Vec<bool> {
+    if roll_total >= 10 && player.landmarks.contains(&LandmarkKind::Harbor) {
+        vec![false, true]
+    } else {
+        vec![false]
+    }
+}
+
+// Whether the player may keep their roll (false) and roll again (true).
+pub fn get_legal_rerolls(player: &Player) -> Vec<bool> {
+    if player.landmarks.contains(&LandmarkKind::RadioTower) {
+        vec![false, true]
+    } else {
+        vec![false]
+    }
+}
+
+// Every purchase the player can make, starting with passing.
+pub fn get_legal_purchases(
+    player: &Player,
+    card_deck: &Deck,
+    config: &GameConfig,
+) -> Vec<Purchase> {
+    let cards = card_deck.iter().map(|card| Purchase::Card(card.kind));
+    let landmarks = config.get_landmarks().into_iter().map(Purchase::Landmark);
+    [Purchase::Pass]
+        .into_iter()
+        .chain(cards)
+        .chain(landmarks)
+        .filter(|purchase| is_legal_purchase(player, card_deck, config, purchase))
+        .collect()
+}
+
+// Whether the player may invest a coin in their Tech Startup.
+#[cfg(feature = "millionaires-row")]
+pub fn get_legal_investments(player: &Player) -> Vec<bool> {
+    if player.cards.get_count(CardKind::TechStartup) > 0 && player.coins > 0 {
+        vec![false, true]
+    } else {
+        vec![false]
+    }
+}
+
+// Every establishment the owner can give away, as the other player and the owner's card.
+#[cfg(feature = "millionaires-row")]
+pub fn get_legal_gifts(players: &[Player], owner_turn: usize) -> Vec<(usize, CardKind)> {
+    let owner_cards: Vec<CardKind> = match players.get(owner_turn) {
+        Some(owner) => owner
+            .cards
+            .iter()
+            .filter(|card| card.count > 0 && card.get_icon() != CardIcon::Major)
+            .map(|card| card.kind)
+            .collect(),
+        None => return Vec::new(),
+    };
+    get_legal_steal_targets(players, owner_turn)
+        .into_iter()
+        .flat_map(|other_turn| owner_cards.iter().map(move |card| (other_turn, *card)))
+        .collect()
+}
+
+// The landmarks the player can demolish.
+#[cfg(feature = "millionaires-row")]
+pub fn get_legal_demolitions(player: &Player) -> Vec<LandmarkKind> {
+    player
+        .landmarks
+        .iter()
+        .filter(|landmark| !is_landmark_built_at_start(landmark))
+        .cloned()
+        .collect()
+}
+
+// The non-Major establishments owned and open for business by any player.
+#[cfg(feature = "millionaires-row")]
+pub fn get_legal_renovations(players: &[Player]) -> Vec<CardKind> {
+    get_all_cards()
+        .filter(|card_kind| {
+            get_card_icon(*card_kind) != CardIcon::Major
+                && players.iter().any(|player| {
+                    player
+                        .cards
+                        .get(*card_kind)
+                        .is_some_and(|card| card.count > 0 && !card.closed)
+                })
+        })
+        .collect()
+}
+
+// The establishments the Exhibit Hall can activate: the owner's open non-Major establishments
+// that activate on the owner's turn.
+#[cfg(feature = "millionaires-row")]
+pub fn get_legal_exhibits(player: &Player) -> Vec<CardKind> {
+    player
+        .cards
+        .iter()
+        .filter(|card| {
+            card.count > 0
+                && !card.closed
+                && card.get_icon() != CardIcon::Major
+                && card.get_order() != CardOrder::Restaurants
+        })
+        .map(|card| card.kind)
+        .collect()
+}
+
+// The players the owner can take coins from.
+pub fn get_legal_steal_targets(players: &[Player], owner_turn: usize) -> Vec<usize> {
+    players
+        .iter()
+        .filter(|player| player.turn != owner_turn)
+        .map(|player| player.turn)
+        .collect()
+}
+
+// Every trade the owner can make, as the other player, their card and the owner's card.
+pub fn get_legal_trades(players: &[Player], owner_turn: usize) -> Vec<(usize, CardKind, CardKind)> {
+    let get_tradable_cards = |player: &Player| -> Vec<CardKind> {
+        player
+            .cards
+            .iter()
+            .filter(|card| card.count > 0 && card.get_icon() != CardIcon::Major)
+            .map(|card| card.kind)
+            .collect()
+    };
+    let player_cards = match players.get(owner_turn) {
+        Some(player) => get_tradable_cards(player),
+        None => return vec![],
+    };
+
+    let mut trades = vec![];
+    for other_player in players.iter().filter(|player| player.turn != owner_turn) {
+        for other_player_card in get_tradable_cards(other_player) {
+            for player_card in player_cards.iter() {
+                trades.push((other_player.turn, other_player_card, *player_card));
+            }
+        }
+    }
+    trades
 }
 
 pub fn buy_card_from_deck(
-    players: &mut Vec<Player>,
+    players: &mut [Player],
     player_turn: usize,
     card_deck: &mut Deck,
+    config: &GameConfig,
     card_kind: CardKind,
-) {
+) -> Result<u8, GameError> {
+    check_card_purchase(
+        get_player(players, player_turn)?,
+        card_deck,
+        config,
+        card_kind,
+    )?;
     let card = card_deck
-        .iter_mut()
-        .find(|card| card.kind == card_kind)
-        .expect("Expect to find card entry in deck.");
-    remove_card_from_stack(card, 1);
-    take_coins(players, player_turn, card.get_cost());
-    add_player_card(players, player_turn, card_kind, 1);
+        .get_mut(card_kind)
+        .ok_or(GameError::CardNotFound(card_kind))?;
+    remove_card_from_stack(card, 1)?;
+    let cost = take_coins(players, player_turn, card.get_cost())?;
+    add_player_card(players, player_turn, card_kind, 1)?;
+    Ok(cost)
 }
 
-pub fn buy_landmark(players: &mut Vec<Player>, player_turn: usize, landmark_kind: LandmarkKind) {
-    take_coins(players, player_turn, get_landmark_cost(&landmark_kind));
-    let player_mut = players
-        .get_mut(player_turn)
-        .expect("Player to be in bounds.");
-    if player_mut.landmarks.contains(&landmark_kind) {
-        panic!("Expected to not duplicate a landmark.");
-    }
-    player_mut.landmarks.push(landmark_kind);
+pub fn buy_landmark(
+    players: &mut [Player],
+    player_turn: usize,
+    config: &GameConfig,
+    landmark_kind: LandmarkKind,
+) -> Result<u8, GameError> {
+    check_landmark_purchase(get_player(players, player_turn)?, config, &landmark_kind)?;
+    let cost = take_coins(
+        players,
+        player_turn,
+        config.get_landmark_cost(&landmark_kind),
+    )?;
+    get_player_mut(players, player_turn)?
+        .landmarks
+        .push(landmark_kind);
+    Ok(cost)
 }
 
 pub fn add_player_card(
-    players: &mut Vec<Player>,
+    players: &mut [Player],
     player_turn: usize,
     card_kind: CardKind,
     amount: u8,
-) {
-    let player_mut = players
-        .get_mut(player_turn)
-        .expect("Player to be in bounds.");
-    let mut player_card = player_mut
+) -> Result<(), GameError> {
+    let player_card = get_player_mut(players, player_turn)?
         .cards
-        .iter_mut()
-        .find(|card| card.kind == card_kind)
-        .expect("To find the card kind.");
+        .get_or_insert_mut(card_kind);
     player_card.count = u8::saturating_add(player_card.count, amount);
+    Ok(())
 }
 
 pub fn remove_player_card(
-    players: &mut Vec<Player>,
+    players: &mut [Player],
     player_turn: usize,
     card_kind: CardKind,
     amount: u8,
-) {
+) -> Result<(), GameError> {
     remove_card_from_stack(
-        players
-            .get_mut(player_turn)
-            .expect("Player to be in bounds.")
-            .cards
-            .iter_mut()
-            .find(|card| card.kind == card_kind)
-            .expect("To find the card kind."),
+        get_player_card_mut(get_player_mut(players, player_turn)?, card_kind)?,
         amount,
-    );
+    )
+}
+
+pub fn get_player(players: &[Player], player_turn: usize) -> Result<&Player, GameError> {
+    players
+        .get(player_turn)
+        .ok_or(GameError::PlayerNotFound(player_turn))
+}
+
+pub fn get_player_mut(
+    players: &mut [Player],
+    player_turn: usize,
+) -> Result<&mut Player, GameError> {
+    players
+        .get_mut(player_turn)
+        .ok_or(GameError::PlayerNotFound(player_turn))
+}
+
+fn get_player_card(player: &Player, card_kind: CardKind) -> Result<&CardStack, GameError> {
+    player
+        .cards
+        .get(card_kind)
+        .ok_or(GameError::CardNotFound(card_kind))
+}
+
+fn get_player_card_mut(
+    player: &mut Player,
+    card_kind: CardKind,
+) -> Result<&mut CardStack, GameError> {
+    player
+        .cards
+        .get_mut(card_kind)
+        .ok_or(GameError::CardNotFound(card_kind))
 }
 
 pub fn get_activatable_cards(
     roll_total: u8,
     player_turn: usize,
-    players: &Vec<Player>,
+    players: &[Player],
 ) -> Vec<PlayerCardStack> {
+    let roller = players.get(player_turn);
     let mut activatable_cards: Vec<PlayerCardStack> = players
         .iter()
         .flat_map(|player| {
             player
                 .cards
-                .into_iter()
+                .iter()
                 .filter(|card| {
-                    card.count > 0 && card.test_activation(roll_total, player.turn == player_turn)
+                    card.count > 0
+                        && card.test_activation(roll_total, player.turn == player_turn)
+                        && card
+                            .get_required_landmark()
+                            .is_none_or(|landmark| player.landmarks.contains(&landmark))
+                        && test_card_condition(card, player, roller)
                 })
-                .map(|cards| PlayerCardStack {
+                .map(|card| PlayerCardStack {
                     owner_turn: player.turn,
-                    card: cards,
+                    card: *card,
                 })
                 .collect::<Vec<PlayerCardStack>>()
         })
@@ -197,30 +766,38 @@ pub fn get_activatable_cards(
     activatable_cards
 }
 
-pub fn create_deck() -> Deck {
-    ALL_CARDS.map(|kind| CardStack {
-        count: match kind {
-            CardKind::BusinessCenter | CardKind::Stadium | CardKind::TvStation => 4,
-            _ => 6,
-        },
-        kind,
-    })
+// Whether enough landmarks are built for the card to activate.
+fn test_card_condition(card: &CardStack, owner: &Player, roller: Option<&Player>) -> bool {
+    match get_card_definition(card.kind).condition {
+        Some(CardCondition::OwnerLandmarksBelow { landmarks }) => {
+            count_built_landmarks(owner) < landmarks
+        }
+        Some(CardCondition::RollerLandmarksAtLeast { landmarks }) => {
+            roller.is_some_and(|roller| count_built_landmarks(roller) >= landmarks)
+        }
+        None => true,
+    }
 }
 
-pub fn create_player_deck() -> Deck {
-    ALL_CARDS.map(|kind| CardStack {
-        count: match kind {
-            CardKind::WheatField | CardKind::Bakery => 1,
-            _ => 0,
-        },
-        kind,
-    })
+pub fn create_deck(config: &GameConfig) -> Deck {
+    get_all_cards()
+        .map(|kind| CardStack::new(kind, config.get_supply_count(kind)))
+        .collect()
+}
+
+pub fn create_player_deck(config: &GameConfig) -> Deck {
+    get_all_cards()
+        .map(|kind| CardStack::new(kind, config.get_starting_count(kind)))
+        .collect()
 }
 
 pub type DiceRoll = (u8, Option<u8>);
 
-pub fn roll_dice(roll_two_dice: bool) -> DiceRoll {
-    let mut rng = rand::thread_rng();
+// The random number generator used for dice and computer decisions. ChaCha produces the same
+// sequence on every platform, so a seed is enough to reproduce a game.
+pub type GameRng = ChaCha8Rng;
+
+pub fn roll_dice(rng: &mut GameRng, roll_two_dice: bool) -> DiceRoll {
     (
         rng.gen_range(1..=6),
         if roll_two_dice {
@@ -231,26 +808,87 @@ pub fn roll_dice(roll_two_dice: bool) -> DiceRoll {
     )
 }
 
-pub fn has_player_won(player: &Player) -> bool {
-    ALL_LANDMARKS
-        .iter()
-        .all(|landmark| player.landmarks.contains(landmark))
+pub fn has_player_won(player: &Player, config: &GameConfig) -> bool {
+    let landmarks_to_win = config
+        .landmarks_to_win
+        .unwrap_or_else(|| config.get_landmarks().len());
+    player.landmarks.len() >= landmarks_to_win
+}
+
+fn check_card_purchase(
+    player: &Player,
+    card_deck: &Deck,
+    config: &GameConfig,
+    card_kind: CardKind,
+) -> Result<(), GameError> {
+    let card = card_deck
+        .get(card_kind)
+        .ok_or(GameError::CardNotFound(card_kind))?;
+    if card.count == 0 {
+        return Err(GameError::NotEnoughCards(card_kind));
+    }
+    if let Some(limit) = config.get_card_limit(card_kind) {
+        if player.cards.get_count(card_kind) >= limit {
+            return Err(GameError::CardLimitReached(card_kind));
+        }
+    }
+    check_cost(player, card.get_cost())
+}
+
+fn check_landmark_purchase(
+    player: &Player,
+    config: &GameConfig,
+    landmark_kind: &LandmarkKind,
+) -> Result<(), GameError> {
+    if player.landmarks.contains(landmark_kind) {
+        return Err(GameError::DuplicateLandmark(landmark_kind.clone()));
+    }
+    if !config.is_landmark_in_play(landmark_kind) {
+        return Err(GameError::LandmarkNotInPlay(landmark_kind.clone()));
+    }
+    check_cost(player, config.get_landmark_cost(landmark_kind))
 }
 
-fn remove_card_from_stack(card: &mut CardStack, amount: u8) {
-    card.count = u8::checked_sub(card.count, amount).expect("To not remove more cards than exist.");
+fn check_cost(player: &Player, cost: u8) -> Result<(), GameError> {
+    if can_afford(player, cost) {
+        Ok(())
+    } else {
+        Err(GameError::NotEnoughCoins {
+            cost,
+            coins: player.coins,
+        })
+    }
+}
+
+fn remove_card_from_stack(card: &mut CardStack, amount: u8) -> Result<(), GameError> {
+    card.count = u8::checked_sub(card.count, amount).ok_or(GameError::NotEnoughCards(card.kind))?;
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use crate::game::{
-        cards::{CardIcon, CardKind, CardStack, ALL_CARDS},
-        player::{Player, PlayerKind},
+        cards::{get_all_cards, CardIcon, CardKind, CardStack},
+        config::GameConfig,
+        landmarks::LandmarkKind,
+        player::Player,
     };
 
     use super::{
-        award_coins_combo, buy_card_from_deck, create_deck, get_activatable_cards, steal_coins,
-        steal_coins_from_all, trade_establishments, Deck,
+        award_coins_combo, buy_card_from_deck, buy_landmark, create_deck, get_activatable_cards,
+        get_legal_purchases, get_legal_rerolls, get_legal_roll_two_dice, get_legal_steal_targets,
+        get_legal_trades, is_legal_purchase, steal_coins, steal_coins_from_all,
+        trade_establishments, Deck, GameError, Purchase,
+    };
+    #[cfg(feature = "harbor")]
+    use super::{
+        check_purchase, get_legal_add_two, steal_coins_per_icon_from_all,
+        steal_half_coins_from_rich,
+    };
+    #[cfg(feature = "millionaires-row")]
+    use super::{
+        demolish_landmark, get_legal_demolitions, get_legal_renovations, give_establishment,
+        renovate_establishments, share_coins_evenly,
     };
 
     #[test]
@@ -281,7 +919,7 @@ mod tests {
     #[test]
     fn test_steal_exact_coins() {
         let mut players = get_players();
-        let total_stolen = steal_coins(&mut players, 2, 0, 3);
+        let total_stolen = steal_coins(&mut players, 2, 0, 3).expect("Coins to be stolen.");
         assert_eq!(total_stolen, 3);
         assert_eq!(players[2].coins, 0);
         assert_eq!(players[0].coins, 4);
@@ -290,7 +928,7 @@ mod tests {
     #[test]
     fn test_steal_more_coins() {
         let mut players = get_players();
-        let total_stolen = steal_coins(&mut players, 2, 0, 1);
+        let total_stolen = steal_coins(&mut players, 2, 0, 1).expect("Coins to be stolen.");
         assert_eq!(total_stolen, 1);
         assert_eq!(players[2].coins, 2);
         assert_eq!(players[0].coins, 2);
@@ -299,7 +937,7 @@ mod tests {
     #[test]
     fn test_steal_less_coins() {
         let mut players = get_players();
-        let total_stolen = steal_coins(&mut players, 0, 1, 2);
+        let total_stolen = steal_coins(&mut players, 0, 1, 2).expect("Coins to be stolen.");
         assert_eq!(total_stolen, 1);
         assert_eq!(players[0].coins, 0);
         assert_eq!(players[1].coins, 1);
@@ -308,7 +946,7 @@ mod tests {
     #[test]
     fn test_steal_no_coins() {
         let mut players = get_players();
-        let total_stolen = steal_coins(&mut players, 1, 0, 2);
+        let total_stolen = steal_coins(&mut players, 1, 0, 2).expect("Coins to be stolen.");
         assert_eq!(total_stolen, 0);
         assert_eq!(players[1].coins, 0);
         assert_eq!(players[0].coins, 1);
@@ -317,18 +955,193 @@ mod tests {
     #[test]
     fn test_steal_coins_from_all() {
         let mut players = get_players();
-        let player_count = players.iter().count();
-        let total_stolen = steal_coins_from_all(&mut players, 0, player_count, 1);
-        assert_eq!(total_stolen, 1);
+        let player_count = players.len();
+        let coins_stolen =
+            steal_coins_from_all(&mut players, 0, player_count, 1).expect("Coins to be stolen.");
+        assert_eq!(coins_stolen, vec![(1, 0), (2, 1), (3, 0)]);
         assert_eq!(players[0].coins, 2);
         assert_eq!(players[1].coins, 0);
         assert_eq!(players[2].coins, 2);
     }
 
+    #[test]
+    #[cfg(feature = "harbor")]
+    fn test_steal_coins_per_icon_from_all() {
+        let mut players = get_players();
+        players[1].coins = 5;
+        players[3].coins = 1;
+        let player_count = players.len();
+        let coins_stolen = steal_coins_per_icon_from_all(
+            &mut players,
+            0,
+            player_count,
+            &[CardIcon::Cup, CardIcon::Bread],
+            1,
+        )
+        .expect("Coins to be stolen.");
+        assert_eq!(coins_stolen, vec![(1, 3), (2, 1), (3, 1)]);
+        assert_eq!(players[0].coins, 6);
+        assert_eq!(players[1].coins, 2);
+    }
+
+    #[test]
+    #[cfg(feature = "harbor")]
+    fn test_steal_half_coins_from_rich() {
+        let mut players = get_players();
+        players[1].coins = 15;
+        players[3].coins = 10;
+        let player_count = players.len();
+        let coins_stolen = steal_half_coins_from_rich(&mut players, 0, player_count, 10)
+            .expect("Coins to be stolen.");
+        assert_eq!(coins_stolen, vec![(1, 7), (3, 5)]);
+        assert_eq!(players[0].coins, 13);
+        assert_eq!(players[1].coins, 8);
+        assert_eq!(players[2].coins, 3);
+    }
+
+    #[test]
+    #[cfg(feature = "millionaires-row")]
+    fn test_share_coins_evenly() {
+        let mut players = get_players();
+        players[3].coins = 3;
+        assert_eq!(
+            share_coins_evenly(&mut players),
+            vec![(0, 1), (1, 2), (2, -1), (3, -1)]
+        );
+        assert!(players.iter().all(|player| player.coins == 2));
+    }
+
+    #[test]
+    #[cfg(feature = "millionaires-row")]
+    fn test_renovate_establishments() {
+        let mut players = get_players();
+        assert_eq!(
+            renovate_establishments(&mut players, CardKind::Stadium),
+            Err(GameError::CannotRenovate(CardKind::Stadium))
+        );
+        assert_eq!(
+            renovate_establishments(&mut players, CardKind::WheatField),
+            Ok(vec![(0, 1), (1, 1), (2, 3), (3, 1)])
+        );
+        assert!(!get_legal_renovations(&players).contains(&CardKind::WheatField));
+
+        // Closed establishments still come up so that they can reopen.
+        let card_activations = get_activatable_cards(1, 0, &players);
+        assert_eq!(card_activations.len(), 4);
+        assert!(card_activations
+            .iter()
+            .all(|card_stack| card_stack.card.closed));
+    }
+
+    #[test]
+    #[cfg(feature = "millionaires-row")]
+    fn test_landmark_conditions() {
+        let mut players = get_players();
+        players[1].cards.set_count(CardKind::FrenchRestaurant, 1);
+        let has_french_restaurant = |players: &[Player]| {
+            get_activatable_cards(5, 0, players)
+                .iter()
+                .any(|card_stack| card_stack.card.kind == CardKind::FrenchRestaurant)
+        };
+        players[0].landmarks = vec![LandmarkKind::TrainStation];
+        assert!(!has_french_restaurant(&players));
+        players[0].landmarks.push(LandmarkKind::ShoppingMall);
+        assert!(has_french_restaurant(&players));
+    }
+
+    #[test]
+    #[cfg(feature = "millionaires-row")]
+    fn test_demolish_landmark() {
+        let mut players = get_players();
+        players[0].landmarks = vec![LandmarkKind::TrainStation];
+        assert_eq!(
+            get_legal_demolitions(&players[0]),
+            vec![LandmarkKind::TrainStation]
+        );
+        assert_eq!(
+            demolish_landmark(&mut players, 0, &LandmarkKind::ShoppingMall),
+            Err(GameError::CannotDemolish(LandmarkKind::ShoppingMall))
+        );
+        demolish_landmark(&mut players, 0, &LandmarkKind::TrainStation)
+            .expect("Landmark to be demolished.");
+        assert!(players[0].landmarks.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "millionaires-row")]
+    fn test_give_establishment() {
+        let mut players = get_players();
+        give_establishment(&mut players, 0, 1, CardKind::Mine).expect("Card to be given.");
+        assert_eq!(
+            find_card_in_deck(&players[0].cards, CardKind::Mine).count,
+            1
+        );
+        assert_eq!(
+            find_card_in_deck(&players[1].cards, CardKind::Mine).count,
+            1
+        );
+        assert_eq!(
+            give_establishment(&mut players, 3, 1, CardKind::BusinessCenter),
+            Err(GameError::MajorEstablishmentTrade(CardKind::BusinessCenter))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "harbor")]
+    fn test_harbor_cards_require_harbor() {
+        let mut players = get_players();
+        players[1].cards.set_count(CardKind::SushiBar, 1);
+        let has_sushi_bar = |players: &[Player]| {
+            get_activatable_cards(1, 0, players)
+                .iter()
+                .any(|card_stack| card_stack.card.kind == CardKind::SushiBar)
+        };
+        assert!(!has_sushi_bar(&players));
+        players[1].landmarks.push(LandmarkKind::Harbor);
+        assert!(has_sushi_bar(&players));
+    }
+
+    #[test]
+    #[cfg(feature = "harbor")]
+    fn test_get_legal_add_two() {
+        let mut players = get_players();
+        assert_eq!(get_legal_add_two(&players[0], 11), vec![false]);
+        players[0].landmarks.push(LandmarkKind::Harbor);
+        assert_eq!(get_legal_add_two(&players[0], 9), vec![false]);
+        assert_eq!(get_legal_add_two(&players[0], 10), vec![false, true]);
+    }
+
+    #[test]
+    #[cfg(feature = "harbor")]
+    fn test_buy_landmark_not_in_play() {
+        let mut players = get_players();
+        players[0].coins = 30;
+        let card_deck = create_deck(&GameConfig::default());
+        assert_eq!(
+            check_purchase(
+                &players[0],
+                &card_deck,
+                &GameConfig::default(),
+                &Purchase::Landmark(LandmarkKind::Airport)
+            ),
+            Err(GameError::LandmarkNotInPlay(LandmarkKind::Airport))
+        );
+        assert_eq!(
+            check_purchase(
+                &players[0],
+                &card_deck,
+                &GameConfig::default(),
+                &Purchase::Card(CardKind::TunaBoat)
+            ),
+            Err(GameError::NotEnoughCards(CardKind::TunaBoat))
+        );
+    }
+
     #[test]
     fn test_award_coins_combo() {
         let mut players = get_players();
-        let total_earned = award_coins_combo(&mut players, 2, CardIcon::Wheat, 2);
+        let total_earned =
+            award_coins_combo(&mut players, 2, CardIcon::Wheat, 2).expect("Coins to be awarded.");
         assert_eq!(total_earned, 6);
         assert_eq!(players[2].coins, 9);
     }
@@ -342,7 +1155,8 @@ mod tests {
             1,
             CardKind::Bakery,
             CardKind::FamilyRestaurant,
-        );
+        )
+        .expect("Establishments to be traded.");
         assert_eq!(
             find_card_in_deck(&players[2].cards, CardKind::Bakery).count,
             0
@@ -362,23 +1176,243 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Should not be able to trade Major establishments.")]
     fn test_trade_major_establishments() {
         let mut players = get_players();
-        trade_establishments(
+        let result = trade_establishments(
             &mut players,
             3,
             0,
             CardKind::BusinessCenter,
             CardKind::WheatField,
         );
+        assert_eq!(
+            result,
+            Err(GameError::MajorEstablishmentTrade(CardKind::BusinessCenter))
+        );
+        assert_eq!(
+            find_card_in_deck(&players[3].cards, CardKind::BusinessCenter).count,
+            1
+        );
+    }
+
+    #[test]
+    fn test_trade_unowned_establishment() {
+        let mut players = get_players();
+        let result = trade_establishments(&mut players, 2, 0, CardKind::Bakery, CardKind::Cafe);
+        assert_eq!(result, Err(GameError::NotEnoughCards(CardKind::Cafe)));
+        assert_eq!(
+            find_card_in_deck(&players[2].cards, CardKind::Bakery).count,
+            1
+        );
+        assert_eq!(
+            find_card_in_deck(&players[0].cards, CardKind::Cafe).count,
+            0
+        );
+    }
+
+    #[test]
+    fn test_steal_from_missing_player() {
+        let mut players = get_players();
+        assert_eq!(
+            steal_coins(&mut players, 7, 0, 5),
+            Err(GameError::PlayerNotFound(7))
+        );
+        assert_eq!(players[0].coins, 1);
+    }
+
+    #[test]
+    fn test_buy_duplicate_landmark() {
+        let mut players = get_players();
+        players[2].coins = 10;
+        buy_landmark(
+            &mut players,
+            2,
+            &GameConfig::default(),
+            LandmarkKind::TrainStation,
+        )
+        .expect("Landmark to be built.");
+        assert_eq!(
+            buy_landmark(
+                &mut players,
+                2,
+                &GameConfig::default(),
+                LandmarkKind::TrainStation
+            ),
+            Err(GameError::DuplicateLandmark(LandmarkKind::TrainStation))
+        );
+        assert_eq!(players[2].coins, 6);
+    }
+
+    #[test]
+    fn test_buy_card_from_empty_stack() {
+        let mut players = get_players();
+        players[2].coins = 30;
+        let mut card_deck = create_deck(&GameConfig::default());
+        let config = GameConfig {
+            limit_major_establishments: false,
+            ..GameConfig::default()
+        };
+        for _ in 0..4 {
+            buy_card_from_deck(&mut players, 2, &mut card_deck, &config, CardKind::Stadium)
+                .expect("Card to be bought.");
+        }
+        assert_eq!(
+            buy_card_from_deck(&mut players, 2, &mut card_deck, &config, CardKind::Stadium),
+            Err(GameError::NotEnoughCards(CardKind::Stadium))
+        );
+    }
+
+    #[test]
+    fn test_buy_second_major_establishment() {
+        let mut players = get_players();
+        players[3].coins = 30;
+        let mut card_deck = create_deck(&GameConfig::default());
+        assert_eq!(
+            buy_card_from_deck(
+                &mut players,
+                3,
+                &mut card_deck,
+                &GameConfig::default(),
+                CardKind::BusinessCenter
+            ),
+            Err(GameError::CardLimitReached(CardKind::BusinessCenter))
+        );
+        buy_card_from_deck(
+            &mut players,
+            3,
+            &mut card_deck,
+            &GameConfig::default(),
+            CardKind::Stadium,
+        )
+        .expect("Card to be bought.");
+        assert!(!is_legal_purchase(
+            &players[3],
+            &card_deck,
+            &GameConfig::default(),
+            &Purchase::Card(CardKind::Stadium)
+        ));
+        assert!(is_legal_purchase(
+            &players[3],
+            &card_deck,
+            &GameConfig {
+                limit_major_establishments: false,
+                ..GameConfig::default()
+            },
+            &Purchase::Card(CardKind::Stadium)
+        ));
+    }
+
+    #[test]
+    fn test_buy_unaffordable_card() {
+        let mut players = get_players();
+        let mut card_deck = create_deck(&GameConfig::default());
+        assert_eq!(
+            buy_card_from_deck(
+                &mut players,
+                2,
+                &mut card_deck,
+                &GameConfig::default(),
+                CardKind::Stadium
+            ),
+            Err(GameError::NotEnoughCoins { cost: 6, coins: 3 })
+        );
+        assert_eq!(
+            buy_landmark(
+                &mut players,
+                2,
+                &GameConfig::default(),
+                LandmarkKind::RadioTower
+            ),
+            Err(GameError::NotEnoughCoins { cost: 22, coins: 3 })
+        );
+        assert_eq!(players[2].coins, 3);
+        assert_eq!(find_card_in_deck(&card_deck, CardKind::Stadium).count, 4);
+    }
+
+    #[test]
+    fn test_is_legal_purchase() {
+        let players = get_players();
+        let card_deck = create_deck(&GameConfig::default());
+        assert!(is_legal_purchase(
+            &players[2],
+            &card_deck,
+            &GameConfig::default(),
+            &Purchase::Card(CardKind::AppleOrchard)
+        ));
+        assert!(!is_legal_purchase(
+            &players[2],
+            &card_deck,
+            &GameConfig::default(),
+            &Purchase::Card(CardKind::Stadium)
+        ));
+        assert!(!is_legal_purchase(
+            &players[2],
+            &card_deck,
+            &GameConfig::default(),
+            &Purchase::Landmark(LandmarkKind::TrainStation)
+        ));
+    }
+
+    #[test]
+    fn test_get_legal_purchases() {
+        let mut players = get_players();
+        let card_deck = create_deck(&GameConfig::default());
+        assert_eq!(
+            get_legal_purchases(&players[1], &card_deck, &GameConfig::default()),
+            vec![Purchase::Pass]
+        );
+
+        let purchases = get_legal_purchases(&players[2], &card_deck, &GameConfig::default());
+        assert_eq!(purchases.first(), Some(&Purchase::Pass));
+        assert!(purchases.contains(&Purchase::Card(CardKind::AppleOrchard)));
+        assert!(!purchases.contains(&Purchase::Card(CardKind::Stadium)));
+        assert!(!purchases
+            .iter()
+            .any(|purchase| matches!(purchase, Purchase::Landmark(_))));
+
+        players[2].coins = 4;
+        players[2].landmarks.push(LandmarkKind::TrainStation);
+        assert!(
+            !get_legal_purchases(&players[2], &card_deck, &GameConfig::default())
+                .iter()
+                .any(|purchase| matches!(purchase, Purchase::Landmark(_)))
+        );
+    }
+
+    #[test]
+    fn test_get_legal_trades() {
+        let players = get_players();
+        let trades = get_legal_trades(&players, 3);
+        assert_eq!(trades.len(), 27);
+        assert!(trades.contains(&(0, CardKind::Mine, CardKind::Cafe)));
+        assert!(!trades.iter().any(|trade| trade.0 == 3
+            || trade.1 == CardKind::BusinessCenter
+            || trade.2 == CardKind::BusinessCenter));
+    }
+
+    #[test]
+    fn test_get_legal_dice_decisions() {
+        let mut players = get_players();
+        assert_eq!(get_legal_roll_two_dice(&players[0]), vec![false]);
+        assert_eq!(get_legal_rerolls(&players[0]), vec![false]);
+        players[0].landmarks = vec![LandmarkKind::TrainStation, LandmarkKind::RadioTower];
+        assert_eq!(get_legal_roll_two_dice(&players[0]), vec![false, true]);
+        assert_eq!(get_legal_rerolls(&players[0]), vec![false, true]);
+        assert_eq!(get_legal_steal_targets(&players, 0), vec![1, 2, 3]);
     }
 
     #[test]
     fn test_buy_card_from_deck() {
         let mut players = get_players();
-        let mut card_deck = create_deck();
-        buy_card_from_deck(&mut players, 2, &mut card_deck, CardKind::AppleOrchard);
+        let mut card_deck = create_deck(&GameConfig::default());
+        buy_card_from_deck(
+            &mut players,
+            2,
+            &mut card_deck,
+            &GameConfig::default(),
+            CardKind::AppleOrchard,
+        )
+        .expect("Card to be bought.");
         let card = find_card_in_deck(&players[2].cards, CardKind::AppleOrchard);
         let card_deck = find_card_in_deck(&card_deck, CardKind::AppleOrchard);
         assert_eq!(card.count, 1);
@@ -389,8 +1423,15 @@ mod tests {
     #[test]
     fn test_buy_another_card_from_deck() {
         let mut players = get_players();
-        let mut card_deck = create_deck();
-        buy_card_from_deck(&mut players, 2, &mut card_deck, CardKind::WheatField);
+        let mut card_deck = create_deck(&GameConfig::default());
+        buy_card_from_deck(
+            &mut players,
+            2,
+            &mut card_deck,
+            &GameConfig::default(),
+            CardKind::WheatField,
+        )
+        .expect("Card to be bought.");
         let card = find_card_in_deck(&players[2].cards, CardKind::WheatField);
         let card_deck = find_card_in_deck(&card_deck, CardKind::WheatField);
         assert_eq!(card.count, 4);
@@ -399,74 +1440,84 @@ mod tests {
     }
 
     fn find_card_in_deck(deck: &Deck, card_kind: CardKind) -> &CardStack {
-        deck.iter()
-            .find(|card| card.kind == card_kind)
-            .expect("To find the card.")
+        deck.get(card_kind).expect("To find the card.")
     }
 
     fn get_players() -> Vec<Player> {
         vec![
             Player {
-                cards: ALL_CARDS.map(|kind| CardStack {
-                    count: match kind {
-                        CardKind::WheatField | CardKind::Bakery => 1,
-                        CardKind::Mine => 2,
-                        _ => 0,
-                    },
-                    kind,
-                }),
+                cards: get_all_cards()
+                    .map(|kind| {
+                        CardStack::new(
+                            kind,
+                            match kind {
+                                CardKind::WheatField | CardKind::Bakery => 1,
+                                CardKind::Mine => 2,
+                                _ => 0,
+                            },
+                        )
+                    })
+                    .collect(),
                 landmarks: vec![],
                 name: String::from(""),
-                kind: PlayerKind::Human,
                 turn: 0,
                 coins: 1,
             },
             Player {
-                cards: ALL_CARDS.map(|kind| CardStack {
-                    count: match kind {
-                        CardKind::WheatField
-                        | CardKind::Bakery
-                        | CardKind::FamilyRestaurant
-                        | CardKind::Cafe => 1,
-                        _ => 0,
-                    },
-                    kind,
-                }),
+                cards: get_all_cards()
+                    .map(|kind| {
+                        CardStack::new(
+                            kind,
+                            match kind {
+                                CardKind::WheatField
+                                | CardKind::Bakery
+                                | CardKind::FamilyRestaurant
+                                | CardKind::Cafe => 1,
+                                _ => 0,
+                            },
+                        )
+                    })
+                    .collect(),
                 landmarks: vec![],
                 name: String::from(""),
-                kind: PlayerKind::Human,
                 turn: 1,
                 coins: 0,
             },
             Player {
-                cards: ALL_CARDS.map(|kind| CardStack {
-                    count: match kind {
-                        CardKind::Bakery => 1,
-                        CardKind::WheatField => 3,
-                        _ => 0,
-                    },
-                    kind,
-                }),
+                cards: get_all_cards()
+                    .map(|kind| {
+                        CardStack::new(
+                            kind,
+                            match kind {
+                                CardKind::Bakery => 1,
+                                CardKind::WheatField => 3,
+                                _ => 0,
+                            },
+                        )
+                    })
+                    .collect(),
                 landmarks: vec![],
                 name: String::from(""),
-                kind: PlayerKind::Human,
                 turn: 2,
                 coins: 3,
             },
             Player {
-                cards: ALL_CARDS.map(|kind| CardStack {
-                    count: match kind {
-                        CardKind::Bakery
-                        | CardKind::WheatField
-                        | CardKind::Cafe
-                        | CardKind::BusinessCenter => 1,
-                        _ => 0,
-                    },
-                    kind,
-                }),
+                cards: get_all_cards()
+                    .map(|kind| {
+                        CardStack::new(
+                            kind,
+                            match kind {
+                                CardKind::Bakery
+                                | CardKind::WheatField
+                                | CardKind::Cafe
+                                | CardKind::BusinessCenter => 1,
+                                _ => 0,
+                            },
+                        )
+                    })
+                    .collect(),
                 landmarks: vec![],
                 name: String::from(""),
-                kind: PlayerKind::Human,
                 turn: 3,
                 coins: 0,
             },
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use super::cards::{CardKind, CardStack};
+
+// A collection of establishment stacks keyed by kind, used for both the supply and each player's
+// cards. A kind without a stack counts as having none. Iterates in the order the kinds are
+// declared, which is the order cards are displayed in.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(from = "Vec<CardStack>", into = "Vec<CardStack>")]
+pub struct Deck {
+    stacks: BTreeMap<CardKind, CardStack>,
+}
+
+impl Deck {
+    pub fn get(&self, card_kind: CardKind) -> Option<&CardStack> {
+        self.stacks.get(&card_kind)
+    }
+
+    pub fn get_mut(&mut self, card_kind: CardKind) -> Option<&mut CardStack> {
+        self.stacks.get_mut(&card_kind)
+    }
+
+    // The stack of the kind, added empty if the deck doesn't have one yet.
+    pub fn get_or_insert_mut(&mut self, card_kind: CardKind) -> &mut CardStack {
+        self.stacks
+            .entry(card_kind)
+            .or_insert_with(|| CardStack::new(card_kind, 0))
+    }
+
+    pub fn get_count(&self, card_kind: CardKind) -> u8 {
+        self.get(card_kind).map_or(0, |card| card.count)
+    }
+
+    pub fn set_count(&mut self, card_kind: CardKind, count: u8) {
+        self.get_or_insert_mut(card_kind).count = count;
+    }
+
+    // Replaces the stack of the same kind.
+    pub fn insert(&mut self, card: CardStack) {
+        self.stacks.insert(card.kind, card);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CardStack> {
+        self.stacks.values()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut CardStack> {
+        self.stacks.values_mut()
+    }
+
+    // The total number of cards across every stack.
+    pub fn count_cards(&self) -> usize {
+        self.iter().map(|card| usize::from(card.count)).sum()
+    }
+}
+
+impl FromIterator<CardStack> for Deck {
+    fn from_iter<T: IntoIterator<Item = CardStack>>(cards: T) -> Self {
+        Deck {
+            stacks: cards.into_iter().map(|card| (card.kind, card)).collect(),
+        }
+    }
+}
+
+// Decks are saved as a list of stacks.
+impl From<Vec<CardStack>> for Deck {
+    fn from(cards: Vec<CardStack>) -> Self {
+        cards.into_iter().collect()
+    }
+}
+
+impl From<Deck> for Vec<CardStack> {
+    fn from(deck: Deck) -> Self {
+        deck.stacks.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::cards::{CardKind, CardStack};
+
+    use super::Deck;
+
+    #[test]
+    fn test_deck_lookup() {
+        let mut deck: Deck = [
+            CardStack::new(CardKind::Bakery, 2),
+            CardStack::new(CardKind::WheatField, 1),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(deck.get_count(CardKind::Bakery), 2);
+        assert_eq!(deck.get_count(CardKind::Mine), 0);
+        assert!(deck.get(CardKind::Mine).is_none());
+
+        deck.get_or_insert_mut(CardKind::Mine).count += 1;
+        assert_eq!(deck.get_count(CardKind::Mine), 1);
+        assert_eq!(deck.count_cards(), 4);
+        assert_eq!(
+            deck.iter().map(|card| card.kind).collect::<Vec<CardKind>>(),
+            vec![CardKind::WheatField, CardKind::Bakery, CardKind::Mine]
+        );
+    }
+
+    #[test]
+    fn test_deck_serializes_as_list() {
+        let deck: Deck = [CardStack::new(CardKind::Cafe, 3)].into_iter().collect();
+        let json = serde_json::to_string(&deck).expect("Deck to be serialized.");
+        assert!(json.starts_with('['));
+        let deserialized: Deck = serde_json::from_str(&json).expect("Deck to be deserialized.");
+        assert_eq!(deserialized, deck);
+    }
+}
@@ -0,0 +1,1383 @@
+#[cfg(feature = "harbor")]
+use super::controller::{
+    get_legal_add_two, steal_coins_per_icon_from_all, steal_half_coins_from_rich,
+};
+#[cfg(feature = "millionaires-row")]
+use super::{
+    cards::CardStack,
+    controller::{
+        award_coins_combo_all, award_coins_combo_card, demolish_landmark, get_invested_coins,
+        get_legal_demolitions, get_legal_exhibits, get_legal_gifts, get_legal_investments,
+        get_legal_renovations, give_establishment, invest_coin, is_establishment_closed,
+        renovate_establishments, return_establishment, set_establishments_closed,
+        share_coins_evenly, take_coins,
+    },
+};
+use super::{
+    cards::{CardIcon, CardKind},
+    config::{ConfigError, GameConfig, SupplyMode},
+    controller::{
+        award_coins, award_coins_combo, buy_card_from_deck, buy_landmark, create_deck,
+        get_activatable_cards, get_legal_rerolls, get_legal_roll_two_dice, get_legal_trades,
+        get_player, get_shopping_mall_bonus, has_player_won, roll_dice, steal_coins,
+        steal_coins_from_all, trade_establishments, DiceRoll, GameError, GameRng, PlayerCardStack,
+        Purchase,
+    },
+    deck::Deck,
+    events::{GameEvent, GameEventSubscriber},
+    landmarks::LandmarkKind,
+    marketplace::Marketplace,
+    player::Player,
+    players::{GameView, PlayerStrategy},
+    registry::{get_card_definition, get_card_registry, CardEffect},
+    replay::{Decision, GameRecord},
+    save::{SaveError, SaveFile, SAVE_FILE_VERSION},
+};
+use rand::SeedableRng;
+
+// What happened at the end of a turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnOutcome {
+    NextPlayer,
+    ExtraTurn,
+    Won,
+}
+
+// A game in progress, owning every piece of state needed to play it out.
+pub struct Game {
+    pub players: Vec<Player>,
+    pub strategies: Vec<Box<dyn PlayerStrategy>>,
+    // The establishments for sale. In a marketplace game, only the face-up piles.
+    pub deck: Deck,
+    // The face-down supply, when playing with a marketplace.
+    pub marketplace: Option<Marketplace>,
+    pub config: GameConfig,
+    pub current_turn: usize,
+    pub winner: Option<usize>,
+    pub record: GameRecord,
+    subscribers: Vec<Box<dyn GameEventSubscriber>>,
+    // Dice and strategies draw from separate streams so that the dice stay the same when the
+    // same seed is replayed, no matter how much randomness the strategies consume.
+    dice_rng: GameRng,
+    strategy_rng: GameRng,
+    // The dice the current player has rolled this turn, once they have rolled.
+    roll: Option<DiceRoll>,
+    // The Tuna Boat roll for the current turn, made the first time one activates.
+    #[cfg(feature = "harbor")]
+    tuna_boat_total: Option<u8>,
+}
+
+impl Game {
+    // Creates a new game where each player is controlled by the strategy at the same index.
+    // Games created with the same seed and given the same decisions play out identically.
+    pub fn new(
+        players: Vec<Player>,
+        strategies: Vec<Box<dyn PlayerStrategy>>,
+        seed: u64,
+    ) -> Result<Self, ConfigError> {
+        Game::with_config(players, strategies, seed, GameConfig::default())
+    }
+
+    // Creates a new game like Game::new, played with the given configuration instead of the
+    // published rules. Fails when the configuration is invalid or doesn't allow that many
+    // players.
+    pub fn with_config(
+        players: Vec<Player>,
+        strategies: Vec<Box<dyn PlayerStrategy>>,
+        seed: u64,
+        config: GameConfig,
+    ) -> Result<Self, ConfigError> {
+        assert_eq!(
+            players.len(),
+            strategies.len(),
+            "Expected a strategy for every player."
+        );
+        config.validate()?;
+        if players.len() < 2 || players.len() > config.max_player_count {
+            return Err(ConfigError::Invalid(format!(
+                "a game needs between 2 and {} players, not {}.",
+                config.max_player_count,
+                players.len()
+            )));
+        }
+        if let Some(card_kind) = players
+            .iter()
+            .flat_map(|player| player.cards.iter())
+            .map(|card| card.kind)
+            .find(|card_kind| !get_card_registry().contains(*card_kind))
+        {
+            return Err(ConfigError::Invalid(format!(
+                "{:?} is not one of the establishments being played with.",
+                card_kind
+            )));
+        }
+        let mut deck = create_deck(&config);
+        let mut dice_rng = GameRng::seed_from_u64(seed);
+        let marketplace = match config.supply_mode {
+            SupplyMode::Full => None,
+            SupplyMode::Marketplace => Some(Marketplace::from_deck(
+                &mut deck,
+                config.marketplace_piles,
+                &mut dice_rng,
+            )),
+        };
+        Ok(Game {
+            record: GameRecord::new(seed, &config, &players, &strategies),
+            players,
+            strategies,
+            deck,
+            marketplace,
+            config,
+            current_turn: 0,
+            winner: None,
+            subscribers: Vec::new(),
+            dice_rng,
+            strategy_rng: {
+                let mut rng = GameRng::seed_from_u64(seed);
+                rng.set_stream(1);
+                rng
+            },
+            roll: None,
+            #[cfg(feature = "harbor")]
+            tuna_boat_total: None,
+        })
+    }
+
+    // Restores a saved game, with each player controlled by the strategy at the same index.
+    pub fn from_save_file(
+        save_file: SaveFile,
+        strategies: Vec<Box<dyn PlayerStrategy>>,
+    ) -> Result<Self, SaveError> {
+        save_file.validate()?;
+        if strategies.len() != save_file.players.len() {
+            return Err(SaveError::StrategyCount {
+                players: save_file.players.len(),
+                strategies: strategies.len(),
+            });
+        }
+        Ok(Game {
+            players: save_file.players,
+            strategies,
+            deck: save_file.deck,
+            marketplace: save_file.marketplace,
+            config: save_file.record.config.clone(),
+            current_turn: save_file.current_turn,
+            winner: save_file.winner,
+            record: save_file.record,
+            subscribers: Vec::new(),
+            dice_rng: save_file.dice_rng,
+            strategy_rng: save_file.strategy_rng,
+            roll: None,
+            #[cfg(feature = "harbor")]
+            tuna_boat_total: None,
+        })
+    }
+
+    // Creates a game that carries on from what a strategy can see, e.g. to simulate how the rest
+    // of the game might play out. The view doesn't show a marketplace's face-down cards, so the
+    // new game only sells the piles that are face up. When the current player has already rolled,
+    // call finish_turn to play out the rest of their turn.
+    pub fn from_view(
+        view: &GameView,
+        strategies: Vec<Box<dyn PlayerStrategy>>,
+        seed: u64,
+    ) -> Result<Self, ConfigError> {
+        let mut game = Game::with_config(
+            view.players.to_vec(),
+            strategies,
+            seed,
+            GameConfig {
+                supply_mode: SupplyMode::Full,
+                ..view.config.clone()
+            },
+        )?;
+        game.deck = view.deck.clone();
+        game.current_turn = view.current_turn;
+        game.roll = view.roll;
+        Ok(game)
+    }
+
+    pub fn to_save_file(&self) -> SaveFile {
+        SaveFile {
+            version: SAVE_FILE_VERSION,
+            players: self.players.clone(),
+            strategies: self
+                .strategies
+                .iter()
+                .map(|strategy| strategy.get_id().to_string())
+                .collect(),
+            deck: self.deck.clone(),
+            marketplace: self.marketplace.clone(),
+            current_turn: self.current_turn,
+            winner: self.winner,
+            dice_rng: self.dice_rng.clone(),
+            strategy_rng: self.strategy_rng.clone(),
+            record: self.record.clone(),
+        }
+    }
+
+    pub fn get_current_player(&self) -> &Player {
+        self.players
+            .get(self.current_turn)
+            .expect("Player to not be OOB.")
+    }
+
+    pub fn get_current_strategy(&self) -> &dyn PlayerStrategy {
+        self.strategies
+            .get(self.current_turn)
+            .expect("Strategy to not be OOB.")
+            .as_ref()
+    }
+
+    pub fn get_winner(&self) -> Option<&Player> {
+        self.winner.and_then(|turn| self.players.get(turn))
+    }
+
+    // What the strategies are shown of the game when they make a decision.
+    fn view(&self) -> GameView<'_> {
+        GameView {
+            players: &self.players,
+            deck: &self.deck,
+            config: &self.config,
+            current_turn: self.current_turn,
+            roll: self.roll,
+        }
+    }
+
+    // Asks the player's strategy to make a decision. The strategies and their rng are moved out
+    // while it decides, so that the strategy can be shown a view of the rest of the game.
+    fn decide<T>(
+        &mut self,
+        player_turn: usize,
+        decide: impl FnOnce(&mut dyn PlayerStrategy, &GameView, &mut GameRng) -> T,
+    ) -> T {
+        let mut strategies = std::mem::take(&mut self.strategies);
+        let mut rng = self.strategy_rng.clone();
+        let decision = decide(
+            strategies
+                .get_mut(player_turn)
+                .expect("Strategy to not be OOB.")
+                .as_mut(),
+            &self.view(),
+            &mut rng,
+        );
+        self.strategies = strategies;
+        self.strategy_rng = rng;
+        decision
+    }
+
+    // Plays turns until a player has won, returning the winner.
+    pub fn run_to_completion(&mut self) -> Result<&Player, GameError> {
+        while self.play_turn()? != TurnOutcome::Won {}
+        Ok(self.get_winner().expect("Game to have a winner."))
+    }
+
+    // Registers a subscriber to receive every event emitted from now on.
+    pub fn subscribe(&mut self, subscriber: Box<dyn GameEventSubscriber>) {
+        self.subscribers.push(subscriber);
+    }
+
+    // Plays a single turn for the current player: rolling, resolving cards, purchasing and
+    // checking for a win. Advances to the next player unless an extra turn is earned.
+    // Illegal decisions are rejected with an ActionRejected event and skipped, so an error here
+    // means the game state itself is inconsistent.
+    pub fn play_turn(&mut self) -> Result<TurnOutcome, GameError> {
+        if self.winner.is_some() {
+            return Ok(TurnOutcome::Won);
+        }
+
+        let current_turn = self.current_turn;
+        self.roll = None;
+        #[cfg(feature = "harbor")]
+        {
+            self.tuna_boat_total = None;
+        }
+        self.emit(GameEvent::TurnStarted {
+            player: current_turn,
+        });
+
+        let should_roll_two_dice = if get_legal_roll_two_dice(self.get_current_player()).len() > 1 {
+            let roll_two_dice = self.decide(current_turn, |strategy, view, rng| {
+                strategy.get_roll_two_dice(view, rng)
+            });
+            self.record
+                .decisions
+                .push(Decision::RollTwoDice(roll_two_dice));
+            roll_two_dice
+        } else {
+            false
+        };
+
+        let mut roll = roll_dice(&mut self.dice_rng, should_roll_two_dice);
+        let mut roll_total = roll.0 + roll.1.unwrap_or_default();
+        self.roll = Some(roll);
+        self.emit(GameEvent::DiceRolled {
+            player: current_turn,
+            roll,
+            total: roll_total,
+        });
+
+        if get_legal_rerolls(self.get_current_player()).len() > 1 {
+            let reroll = self.decide(current_turn, |strategy, view, rng| {
+                strategy.ask_reroll(view, roll, rng)
+            });
+            self.record.decisions.push(Decision::Reroll(reroll));
+            if reroll {
+                roll = roll_dice(&mut self.dice_rng, should_roll_two_dice);
+                roll_total = roll.0 + roll.1.unwrap_or_default();
+                self.roll = Some(roll);
+                self.emit(GameEvent::Rerolled {
+                    player: current_turn,
+                    roll,
+                    total: roll_total,
+                });
+            }
+        }
+
+        #[cfg(feature = "harbor")]
+        {
+            roll_total = self.use_harbor(roll_total);
+        }
+
+        let activatable_cards = get_activatable_cards(roll_total, current_turn, &self.players);
+        for card_stack in activatable_cards {
+            self.resolve_card(&card_stack)?;
+        }
+
+        #[cfg(feature = "harbor")]
+        self.use_city_hall()?;
+
+        self.finish_turn()
+    }
+
+    // Plays the rest of the current player's turn once their establishments have been resolved:
+    // purchasing, checking for a win and moving on to the next player.
+    pub fn finish_turn(&mut self) -> Result<TurnOutcome, GameError> {
+        if self.winner.is_some() {
+            return Ok(TurnOutcome::Won);
+        }
+
+        let current_turn = self.current_turn;
+        let purchase_decision = self.decide(current_turn, |strategy, view, rng| {
+            strategy.buy_a_card(view, rng)
+        });
+        self.record
+            .decisions
+            .push(Decision::Purchase(purchase_decision.clone()));
+        #[cfg_attr(not(feature = "harbor"), allow(unused_variables))]
+        let built = match purchase_decision {
+            Purchase::Card(card_kind) => {
+                match buy_card_from_deck(
+                    &mut self.players,
+                    current_turn,
+                    &mut self.deck,
+                    &self.config,
+                    card_kind,
+                ) {
+                    Ok(cost) => {
+                        self.emit(GameEvent::CardBought {
+                            player: current_turn,
+                            card: card_kind,
+                            cost,
+                        });
+                        let loan = get_card_definition(card_kind).loan;
+                        if loan > 0 {
+                            self.award_coins(current_turn, loan, card_kind)?;
+                        }
+                        self.refill_marketplace();
+                        true
+                    }
+                    Err(error) => {
+                        self.reject_action(current_turn, error);
+                        false
+                    }
+                }
+            }
+            Purchase::Landmark(landmark_kind) => {
+                match buy_landmark(
+                    &mut self.players,
+                    current_turn,
+                    &self.config,
+                    landmark_kind.clone(),
+                ) {
+                    Ok(cost) => {
+                        self.emit(GameEvent::LandmarkBuilt {
+                            player: current_turn,
+                            landmark: landmark_kind,
+                            cost,
+                        });
+                        true
+                    }
+                    Err(error) => {
+                        self.reject_action(current_turn, error);
+                        false
+                    }
+                }
+            }
+            Purchase::Pass => {
+                self.emit(GameEvent::PurchaseSkipped {
+                    player: current_turn,
+                });
+                false
+            }
+        };
+
+        #[cfg(feature = "harbor")]
+        self.use_airport(built)?;
+        #[cfg(feature = "millionaires-row")]
+        self.ask_invest();
+
+        let player = self.get_current_player();
+        if has_player_won(player, &self.config) {
+            self.winner = Some(current_turn);
+            self.emit(GameEvent::GameWon {
+                player: current_turn,
+            });
+            return Ok(TurnOutcome::Won);
+        }
+
+        if player.landmarks.contains(&LandmarkKind::AmusementPark)
+            && self.roll.is_some_and(|roll| Some(roll.0) == roll.1)
+        {
+            self.emit(GameEvent::ExtraTurn {
+                player: current_turn,
+            });
+            return Ok(TurnOutcome::ExtraTurn);
+        }
+
+        self.current_turn += 1;
+        if self.current_turn >= self.players.len() {
+            self.current_turn = 0;
+        }
+        Ok(TurnOutcome::NextPlayer)
+    }
+
+    fn resolve_card(&mut self, card_stack: &PlayerCardStack) -> Result<(), GameError> {
+        let current_turn = self.current_turn;
+        let owner_turn = card_stack.owner_turn;
+        let card_kind = card_stack.card.kind;
+
+        // Closed establishments reopen instead of activating.
+        #[cfg(feature = "millionaires-row")]
+        if is_establishment_closed(&self.players, owner_turn, card_kind)? {
+            set_establishments_closed(&mut self.players, owner_turn, card_kind, false)?;
+            self.emit(GameEvent::EstablishmentsReopened {
+                player: owner_turn,
+                card: card_kind,
+            });
+            return Ok(());
+        }
+
+        self.emit(GameEvent::CardActivated {
+            owner: owner_turn,
+            card: card_kind,
+            count: card_stack.card.count,
+        });
+
+        let shopping_mall_bonus = get_shopping_mall_bonus(
+            get_player(&self.players, owner_turn)?,
+            card_stack.card.get_icon(),
+        );
+        for _ in 0..card_stack.card.count {
+            match get_card_definition(card_kind).effect {
+                CardEffect::Bank { coins } => {
+                    self.award_coins(owner_turn, coins + shopping_mall_bonus, card_kind)?
+                }
+                CardEffect::TakeFromRoller { coins } => self.steal_coins(
+                    current_turn,
+                    owner_turn,
+                    coins + shopping_mall_bonus,
+                    card_kind,
+                )?,
+                CardEffect::Combo { icon, coins } => {
+                    self.award_coins_combo(owner_turn, icon, coins, card_kind)?
+                }
+                CardEffect::TakeFromAll { coins } => {
+                    let player_count = self.players.len();
+                    for (from_player, amount) in
+                        steal_coins_from_all(&mut self.players, owner_turn, player_count, coins)?
+                    {
+                        self.emit_coins_transferred(
+                            Some(from_player),
+                            Some(owner_turn),
+                            amount,
+                            card_kind,
+                        );
+                    }
+                }
+                CardEffect::TakeFromOne { coins } => {
+                    let from_player = self.decide(owner_turn, |strategy, view, rng| {
+                        strategy.get_player_to_steal_coins_from(view, owner_turn, coins, rng)
+                    });
+                    self.record.decisions.push(Decision::StealFrom(from_player));
+                    if let Err(error) = self.steal_coins(from_player, owner_turn, coins, card_kind)
+                    {
+                        self.reject_action(owner_turn, error);
+                    }
+                }
+                CardEffect::Trade => self.trade_establishments(owner_turn),
+                #[cfg(feature = "harbor")]
+                CardEffect::BankDiceRoll => {
+                    let total = self.get_tuna_boat_total(card_kind);
+                    self.award_coins(owner_turn, total, card_kind)?
+                }
+                #[cfg(feature = "harbor")]
+                CardEffect::TakeFromAllPerIcon { ref icons, coins } => {
+                    let player_count = self.players.len();
+                    for (from_player, amount) in steal_coins_per_icon_from_all(
+                        &mut self.players,
+                        owner_turn,
+                        player_count,
+                        icons,
+                        coins,
+                    )? {
+                        self.emit_coins_transferred(
+                            Some(from_player),
+                            Some(owner_turn),
+                            amount,
+                            card_kind,
+                        );
+                    }
+                }
+                #[cfg(feature = "harbor")]
+                CardEffect::TakeHalfFromRich { threshold } => {
+                    let player_count = self.players.len();
+                    for (from_player, amount) in steal_half_coins_from_rich(
+                        &mut self.players,
+                        owner_turn,
+                        player_count,
+                        threshold,
+                    )? {
+                        self.emit_coins_transferred(
+                            Some(from_player),
+                            Some(owner_turn),
+                            amount,
+                            card_kind,
+                        );
+                    }
+                }
+                #[cfg(feature = "millionaires-row")]
+                CardEffect::ComboCard { card, coins } => {
+                    let amount =
+                        award_coins_combo_card(&mut self.players, owner_turn, card, coins)?;
+                    self.emit_coins_transferred(None, Some(owner_turn), amount, card_kind);
+                }
+                #[cfg(feature = "millionaires-row")]
+                CardEffect::ComboAll { icon, coins } => {
+                    let amount = award_coins_combo_all(&mut self.players, owner_turn, icon, coins)?;
+                    self.emit_coins_transferred(None, Some(owner_turn), amount, card_kind);
+                }
+                #[cfg(feature = "millionaires-row")]
+                CardEffect::TakeAllFromRoller => {
+                    let amount = get_player(&self.players, current_turn)?.coins;
+                    self.steal_coins(current_turn, owner_turn, amount, card_kind)?
+                }
+                #[cfg(feature = "millionaires-row")]
+                CardEffect::PayBank { coins } => {
+                    let amount = take_coins(&mut self.players, owner_turn, coins)?;
+                    self.emit_coins_transferred(Some(owner_turn), None, amount, card_kind);
+                }
+                #[cfg(feature = "millionaires-row")]
+                CardEffect::GiveAway { coins } => self.give_away(owner_turn, coins, card_kind)?,
+                #[cfg(feature = "millionaires-row")]
+                CardEffect::Demolish { coins } => self.demolish(owner_turn, coins, card_kind)?,
+                #[cfg(feature = "millionaires-row")]
+                CardEffect::ShareEvenly => {
+                    for (player_turn, change) in share_coins_evenly(&mut self.players) {
+                        let amount = u8::try_from(change.unsigned_abs()).unwrap_or(u8::MAX);
+                        if change > 0 {
+                            self.emit_coins_transferred(None, Some(player_turn), amount, card_kind);
+                        } else if change < 0 {
+                            self.emit_coins_transferred(Some(player_turn), None, amount, card_kind);
+                        }
+                    }
+                }
+                #[cfg(feature = "millionaires-row")]
+                CardEffect::Renovate { coins } => self.renovate(owner_turn, coins, card_kind)?,
+                #[cfg(feature = "millionaires-row")]
+                CardEffect::TakeInvestedFromAll => {
+                    let amount = get_invested_coins(&self.players, owner_turn)?;
+                    let player_count = self.players.len();
+                    for (from_player, amount) in
+                        steal_coins_from_all(&mut self.players, owner_turn, player_count, amount)?
+                    {
+                        self.emit_coins_transferred(
+                            Some(from_player),
+                            Some(owner_turn),
+                            amount,
+                            card_kind,
+                        );
+                    }
+                }
+                #[cfg(feature = "millionaires-row")]
+                CardEffect::Exhibit => self.exhibit(owner_turn, card_kind)?,
+            };
+        }
+
+        #[cfg(feature = "millionaires-row")]
+        if get_card_definition(card_kind).renovates {
+            set_establishments_closed(&mut self.players, owner_turn, card_kind, true)?;
+            self.emit(GameEvent::EstablishmentsClosed {
+                player: owner_turn,
+                card: card_kind,
+            });
+        }
+        Ok(())
+    }
+
+    // Reveals cards from the marketplace until enough piles are face up again.
+    fn refill_marketplace(&mut self) {
+        let drawn = match self.marketplace.as_mut() {
+            Some(marketplace) => marketplace.refill(&mut self.deck),
+            None => return,
+        };
+        for card_kind in drawn {
+            self.emit(GameEvent::CardRevealed { card: card_kind });
+        }
+    }
+
+    // Lets the owner of a Moving Company give an establishment away, then pays them.
+    #[cfg(feature = "millionaires-row")]
+    fn give_away(
+        &mut self,
+        owner_turn: usize,
+        coins: u8,
+        card_kind: CardKind,
+    ) -> Result<(), GameError> {
+        if get_legal_gifts(&self.players, owner_turn).is_empty() {
+            self.emit(GameEvent::CardHadNoEffect {
+                player: owner_turn,
+                card: card_kind,
+            });
+            return Ok(());
+        }
+
+        let (other_player, given_card_kind) = self.decide(owner_turn, |strategy, view, rng| {
+            strategy.get_establishment_to_give(view, owner_turn, rng)
+        });
+        self.record.decisions.push(Decision::GiveAway {
+            other_player,
+            card: given_card_kind,
+        });
+        match give_establishment(&mut self.players, owner_turn, other_player, given_card_kind) {
+            Ok(()) => {
+                self.emit(GameEvent::EstablishmentGiven {
+                    player: owner_turn,
+                    other_player,
+                    card: given_card_kind,
+                });
+                self.award_coins(owner_turn, coins, card_kind)
+            }
+            Err(error) => {
+                self.reject_action(owner_turn, error);
+                Ok(())
+            }
+        }
+    }
+
+    // Lets the owner of a Demolition Company demolish a landmark, then pays them.
+    #[cfg(feature = "millionaires-row")]
+    fn demolish(
+        &mut self,
+        owner_turn: usize,
+        coins: u8,
+        card_kind: CardKind,
+    ) -> Result<(), GameError> {
+        if get_legal_demolitions(get_player(&self.players, owner_turn)?).is_empty() {
+            self.emit(GameEvent::CardHadNoEffect {
+                player: owner_turn,
+                card: card_kind,
+            });
+            return Ok(());
+        }
+
+        let landmark_kind = self.decide(owner_turn, |strategy, view, rng| {
+            strategy.get_landmark_to_demolish(view, owner_turn, rng)
+        });
+        self.record
+            .decisions
+            .push(Decision::Demolish(landmark_kind.clone()));
+        match demolish_landmark(&mut self.players, owner_turn, &landmark_kind) {
+            Ok(()) => {
+                self.emit(GameEvent::LandmarkDemolished {
+                    player: owner_turn,
+                    landmark: landmark_kind,
+                });
+                self.award_coins(owner_turn, coins, card_kind)
+            }
+            Err(error) => {
+                self.reject_action(owner_turn, error);
+                Ok(())
+            }
+        }
+    }
+
+    // Lets the owner of a Renovation Company close a kind of establishment, then takes coins
+    // from every other player for each of theirs that closed.
+    #[cfg(feature = "millionaires-row")]
+    fn renovate(
+        &mut self,
+        owner_turn: usize,
+        coins: u8,
+        card_kind: CardKind,
+    ) -> Result<(), GameError> {
+        if get_legal_renovations(&self.players).is_empty() {
+            self.emit(GameEvent::CardHadNoEffect {
+                player: owner_turn,
+                card: card_kind,
+            });
+            return Ok(());
+        }
+
+        let renovated_card_kind = self.decide(owner_turn, |strategy, view, rng| {
+            strategy.get_establishment_to_renovate(view, owner_turn, rng)
+        });
+        self.record
+            .decisions
+            .push(Decision::Renovate(renovated_card_kind));
+        let closed = match renovate_establishments(&mut self.players, renovated_card_kind) {
+            Ok(closed) => closed,
+            Err(error) => {
+                self.reject_action(owner_turn, error);
+                return Ok(());
+            }
+        };
+        for (player_turn, count) in closed {
+            self.emit(GameEvent::EstablishmentsClosed {
+                player: player_turn,
+                card: renovated_card_kind,
+            });
+            if player_turn != owner_turn {
+                let amount = u8::saturating_mul(count, coins);
+                self.steal_coins(player_turn, owner_turn, amount, card_kind)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Lets the owner of an Exhibit Hall activate another establishment in its place, returning
+    // the Exhibit Hall to the supply.
+    #[cfg(feature = "millionaires-row")]
+    fn exhibit(&mut self, owner_turn: usize, card_kind: CardKind) -> Result<(), GameError> {
+        let exhibits = get_legal_exhibits(get_player(&self.players, owner_turn)?);
+        if exhibits.is_empty() {
+            self.emit(GameEvent::CardHadNoEffect {
+                player: owner_turn,
+                card: card_kind,
+            });
+            return Ok(());
+        }
+
+        let exhibited_card_kind = self.decide(owner_turn, |strategy, view, rng| {
+            strategy.get_establishment_to_exhibit(view, owner_turn, rng)
+        });
+        self.record
+            .decisions
+            .push(Decision::Exhibit(exhibited_card_kind));
+        let exhibited_card_kind = match exhibited_card_kind {
+            Some(exhibited_card_kind) => exhibited_card_kind,
+            None => return Ok(()),
+        };
+        if !exhibits.contains(&exhibited_card_kind) {
+            self.reject_action(owner_turn, GameError::CannotExhibit(exhibited_card_kind));
+            return Ok(());
+        }
+
+        return_establishment(&mut self.players, owner_turn, &mut self.deck, card_kind)?;
+        self.emit(GameEvent::EstablishmentReturned {
+            player: owner_turn,
+            card: card_kind,
+        });
+        self.resolve_card(&PlayerCardStack {
+            owner_turn,
+            card: CardStack::new(exhibited_card_kind, 1),
+        })
+    }
+
+    fn award_coins(
+        &mut self,
+        player_turn: usize,
+        amount: u8,
+        card_kind: CardKind,
+    ) -> Result<(), GameError> {
+        let amount = award_coins(&mut self.players, player_turn, amount)?;
+        self.emit_coins_transferred(None, Some(player_turn), amount, card_kind);
+        Ok(())
+    }
+
+    // The current player rolls two dice the first time a Tuna Boat activates in a turn, and every
+    // Tuna Boat pays out that same total for the rest of the turn.
+    #[cfg(feature = "harbor")]
+    fn get_tuna_boat_total(&mut self, card_kind: CardKind) -> u8 {
+        if let Some(total) = self.tuna_boat_total {
+            return total;
+        }
+        let roll = roll_dice(&mut self.dice_rng, true);
+        let total = roll.0 + roll.1.unwrap_or_default();
+        self.tuna_boat_total = Some(total);
+        self.emit(GameEvent::BonusDiceRolled {
+            player: self.current_turn,
+            card: card_kind,
+            roll,
+            total,
+        });
+        total
+    }
+
+    // Lets the current player add 2 to a roll of 10 or more with the Harbor. Returns the new
+    // roll total.
+    #[cfg(feature = "harbor")]
+    fn use_harbor(&mut self, roll_total: u8) -> u8 {
+        let current_turn = self.current_turn;
+        if get_legal_add_two(self.get_current_player(), roll_total).len() < 2 {
+            return roll_total;
+        }
+        let add_two = self.decide(current_turn, |strategy, view, rng| {
+            strategy.ask_add_two(view, roll_total, rng)
+        });
+        self.record.decisions.push(Decision::AddTwo(add_two));
+        if !add_two {
+            return roll_total;
+        }
+        let roll_total = roll_total + 2;
+        self.emit(GameEvent::HarborUsed {
+            player: current_turn,
+            total: roll_total,
+        });
+        roll_total
+    }
+
+    // Gives the current player a coin with the City Hall if they have none before buying.
+    #[cfg(feature = "harbor")]
+    fn use_city_hall(&mut self) -> Result<(), GameError> {
+        let player = self.get_current_player();
+        if player.coins == 0 && player.landmarks.contains(&LandmarkKind::CityHall) {
+            self.award_landmark_coins(self.current_turn, LandmarkKind::CityHall, 1)?;
+        }
+        Ok(())
+    }
+
+    // Pays the current player with the Airport if they built nothing this turn.
+    #[cfg(feature = "harbor")]
+    fn use_airport(&mut self, built: bool) -> Result<(), GameError> {
+        if !built
+            && self
+                .get_current_player()
+                .landmarks
+                .contains(&LandmarkKind::Airport)
+        {
+            self.award_landmark_coins(self.current_turn, LandmarkKind::Airport, 10)?;
+        }
+        Ok(())
+    }
+
+    // Lets the current player invest a coin in their Tech Startup at the end of their turn.
+    #[cfg(feature = "millionaires-row")]
+    fn ask_invest(&mut self) {
+        let current_turn = self.current_turn;
+        if get_legal_investments(self.get_current_player()).len() < 2 {
+            return;
+        }
+        let invest = self.decide(current_turn, |strategy, view, rng| {
+            strategy.ask_invest(view, rng)
+        });
+        self.record.decisions.push(Decision::Invest(invest));
+        if invest {
+            match invest_coin(&mut self.players, current_turn) {
+                Ok(()) => self.emit(GameEvent::CoinInvested {
+                    player: current_turn,
+                    card: CardKind::TechStartup,
+                }),
+                Err(error) => self.reject_action(current_turn, error),
+            }
+        }
+    }
+
+    #[cfg(feature = "harbor")]
+    fn award_landmark_coins(
+        &mut self,
+        player_turn: usize,
+        landmark_kind: LandmarkKind,
+        amount: u8,
+    ) -> Result<(), GameError> {
+        self.emit(GameEvent::LandmarkActivated {
+            player: player_turn,
+            landmark: landmark_kind,
+        });
+        let amount = award_coins(&mut self.players, player_turn, amount)?;
+        self.emit(GameEvent::CoinsTransferred {
+            from: None,
+            to: Some(player_turn),
+            amount,
+            card: None,
+        });
+        Ok(())
+    }
+
+    fn award_coins_combo(
+        &mut self,
+        player_turn: usize,
+        card_icon: CardIcon,
+        amount: u8,
+        card_kind: CardKind,
+    ) -> Result<(), GameError> {
+        let amount = award_coins_combo(&mut self.players, player_turn, card_icon, amount)?;
+        self.emit_coins_transferred(None, Some(player_turn), amount, card_kind);
+        Ok(())
+    }
+
+    fn steal_coins(
+        &mut self,
+        from_player_turn: usize,
+        to_player_turn: usize,
+        amount: u8,
+        card_kind: CardKind,
+    ) -> Result<(), GameError> {
+        let amount = steal_coins(&mut self.players, from_player_turn, to_player_turn, amount)?;
+        self.emit_coins_transferred(
+            Some(from_player_turn),
+            Some(to_player_turn),
+            amount,
+            card_kind,
+        );
+        Ok(())
+    }
+
+    // Lets the owner of a Business Center trade an establishment, if any trade is possible.
+    fn trade_establishments(&mut self, owner_turn: usize) {
+        if get_legal_trades(&self.players, owner_turn).is_empty() {
+            self.emit(GameEvent::TradeUnavailable { player: owner_turn });
+            return;
+        }
+
+        let trade = self.decide(owner_turn, |strategy, view, rng| {
+            strategy.get_trade_establishments(view, owner_turn, rng)
+        });
+        let (other_player, other_player_card_kind, player_card_kind) = match trade {
+            Some(trade) => trade,
+            None => {
+                self.record.decisions.push(Decision::DeclineTrade);
+                self.emit(GameEvent::TradeDeclined { player: owner_turn });
+                return;
+            }
+        };
+
+        self.record.decisions.push(Decision::Trade {
+            other_player,
+            other_player_card: other_player_card_kind,
+            player_card: player_card_kind,
+        });
+        match trade_establishments(
+            &mut self.players,
+            owner_turn,
+            other_player,
+            player_card_kind,
+            other_player_card_kind,
+        ) {
+            Ok(()) => self.emit(GameEvent::EstablishmentsTraded {
+                player: owner_turn,
+                other_player,
+                player_card: player_card_kind,
+                other_player_card: other_player_card_kind,
+            }),
+            Err(error) => self.reject_action(owner_turn, error),
+        }
+    }
+
+    // Reports a decision that broke the rules. The decision has no effect on the game.
+    fn reject_action(&mut self, player_turn: usize, error: GameError) {
+        self.emit(GameEvent::ActionRejected {
+            player: player_turn,
+            error,
+        });
+    }
+
+    fn emit_coins_transferred(
+        &mut self,
+        from: Option<usize>,
+        to: Option<usize>,
+        amount: u8,
+        card_kind: CardKind,
+    ) {
+        self.emit(GameEvent::CoinsTransferred {
+            from,
+            to,
+            amount,
+            card: Some(card_kind),
+        });
+    }
+
+    fn emit(&mut self, event: GameEvent) {
+        for subscriber in self.subscribers.iter_mut() {
+            subscriber.on_event(&event, &self.players);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{
+        cards::{CardKind, CardStack},
+        config::{ConfigError, GameConfig},
+        controller::{create_deck, has_player_won, DiceRoll, GameError, GameRng, Purchase},
+        landmarks::LandmarkKind,
+        player::Player,
+        players::{random::RandomAI, GameView, PlayerStrategy},
+    };
+
+    use super::{Decision, Game, GameEvent, TurnOutcome};
+    #[cfg(all(feature = "harbor", feature = "millionaires-row"))]
+    use crate::game::config::Expansion;
+    use crate::game::test_helpers::{get_players, get_strategies};
+    use std::{cell::RefCell, rc::Rc};
+
+    #[test]
+    fn test_play_turn_advances_turn() {
+        let mut game = Game::new(get_players(2, &GameConfig::default()), get_strategies(2), 0)
+            .expect("Config to be valid.");
+        assert_eq!(
+            game.play_turn().expect("Turn to be legal."),
+            TurnOutcome::NextPlayer
+        );
+        assert_eq!(game.current_turn, 1);
+        assert_eq!(
+            game.play_turn().expect("Turn to be legal."),
+            TurnOutcome::NextPlayer
+        );
+        assert_eq!(game.current_turn, 0);
+    }
+
+    #[test]
+    fn test_finish_turn_from_view() {
+        let config = GameConfig::default();
+        let mut players = get_players(2, &GameConfig::default());
+        players[0].landmarks.push(LandmarkKind::AmusementPark);
+        let deck = create_deck(&config);
+        // Rolling doubles earns an extra turn with the Amusement Park.
+        for (roll, outcome, next_turn) in [
+            ((2, Some(2)), TurnOutcome::ExtraTurn, 0),
+            ((2, Some(3)), TurnOutcome::NextPlayer, 1),
+        ] {
+            let view = GameView {
+                players: &players,
+                deck: &deck,
+                config: &config,
+                current_turn: 0,
+                roll: Some(roll),
+            };
+            let mut game =
+                Game::from_view(&view, get_strategies(2), 0).expect("Config to be valid.");
+            assert_eq!(game.finish_turn().expect("Turn to be legal."), outcome);
+            assert_eq!(game.current_turn, next_turn);
+        }
+    }
+
+    #[test]
+    fn test_run_to_completion() {
+        let mut game = Game::new(get_players(3, &GameConfig::default()), get_strategies(3), 0)
+            .expect("Config to be valid.");
+        game.run_to_completion().expect("Game to finish.");
+        let winner = game.get_winner().expect("Game to have a winner.");
+        assert!(has_player_won(winner, &game.config));
+        assert_eq!(
+            game.play_turn().expect("Turn to be legal."),
+            TurnOutcome::Won
+        );
+    }
+
+    #[test]
+    fn test_same_seed_plays_same_game() {
+        let mut game = Game::new(
+            get_players(4, &GameConfig::default()),
+            get_strategies(4),
+            1234,
+        )
+        .expect("Config to be valid.");
+        let mut other_game = Game::new(
+            get_players(4, &GameConfig::default()),
+            get_strategies(4),
+            1234,
+        )
+        .expect("Config to be valid.");
+        game.run_to_completion().expect("Game to finish.");
+        other_game.run_to_completion().expect("Game to finish.");
+        assert_eq!(game.winner, other_game.winner);
+        for (player, other_player) in game.players.iter().zip(other_game.players.iter()) {
+            assert_eq!(player.coins, other_player.coins);
+            assert_eq!(player.cards, other_player.cards);
+        }
+    }
+
+    #[test]
+    fn test_events_account_for_every_coin() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let players = get_players(4, &GameConfig::default());
+        let mut game =
+            Game::new(players.clone(), get_strategies(4), 42).expect("Config to be valid.");
+        let subscriber_events = Rc::clone(&events);
+        game.subscribe(Box::new(move |event: &GameEvent, _: &[Player]| {
+            subscriber_events.borrow_mut().push(event.clone())
+        }));
+        game.run_to_completion().expect("Game to finish.");
+
+        let events = events.borrow();
+        assert_eq!(events.first(), Some(&GameEvent::TurnStarted { player: 0 }));
+        assert_eq!(
+            events.last(),
+            Some(&GameEvent::GameWon {
+                player: game.winner.expect("Game to have a winner.")
+            })
+        );
+
+        let coins = get_coins_from_events(&events, &players);
+        for player in game.players.iter() {
+            assert_eq!(coins[player.turn], i32::from(player.coins));
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "harbor", feature = "millionaires-row"))]
+    fn test_expansion_game() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let config = GameConfig {
+            expansions: [Expansion::Harbor, Expansion::MillionairesRow].into(),
+            ..GameConfig::default()
+        };
+        let players = get_players(4, &config);
+        let mut game = Game::with_config(players.clone(), get_strategies(4), 42, config)
+            .expect("Config to be valid.");
+        let subscriber_events = Rc::clone(&events);
+        game.subscribe(Box::new(move |event: &GameEvent, _: &[Player]| {
+            subscriber_events.borrow_mut().push(event.clone())
+        }));
+        game.run_to_completion().expect("Game to finish.");
+
+        let winner = game.get_winner().expect("Game to have a winner.");
+        assert_eq!(winner.landmarks.len(), 7);
+        let events = events.borrow();
+        assert!(events.contains(&GameEvent::LandmarkActivated {
+            player: 0,
+            landmark: LandmarkKind::CityHall
+        }));
+        let coins = get_coins_from_events(&events, &players);
+        for player in game.players.iter() {
+            assert_eq!(coins[player.turn], i32::from(player.coins));
+        }
+    }
+
+    #[test]
+    fn test_invalid_config_is_rejected() {
+        let config = GameConfig {
+            marketplace_piles: 0,
+            ..GameConfig::default()
+        };
+        assert!(matches!(
+            Game::with_config(
+                get_players(2, &GameConfig::default()),
+                get_strategies(2),
+                0,
+                config
+            ),
+            Err(ConfigError::Invalid(_))
+        ));
+        assert!(matches!(
+            Game::new(get_players(5, &GameConfig::default()), get_strategies(5), 0),
+            Err(ConfigError::Invalid(_))
+        ));
+        assert!(matches!(
+            Game::new(get_players(1, &GameConfig::default()), get_strategies(1), 0),
+            Err(ConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_illegal_purchase_is_rejected() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut players = get_players(2, &GameConfig::default());
+        players[0].coins = 10;
+        players[0].landmarks.push(LandmarkKind::TrainStation);
+        let mut game = Game::new(
+            players,
+            vec![Box::new(TrainStationBuilder), Box::new(RandomAI)],
+            0,
+        )
+        .expect("Config to be valid.");
+        let subscriber_events = Rc::clone(&events);
+        game.subscribe(Box::new(move |event: &GameEvent, _: &[Player]| {
+            subscriber_events.borrow_mut().push(event.clone())
+        }));
+
+        assert_eq!(
+            game.play_turn().expect("Turn to be legal."),
+            TurnOutcome::NextPlayer
+        );
+        assert!(events.borrow().contains(&GameEvent::ActionRejected {
+            player: 0,
+            error: GameError::DuplicateLandmark(LandmarkKind::TrainStation),
+        }));
+        assert_eq!(game.players[0].landmarks, vec![LandmarkKind::TrainStation]);
+    }
+
+    #[test]
+    fn test_trade_with_nothing_to_trade() {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut players = get_players(2, &GameConfig::default());
+        players[0].cards = [CardStack::new(CardKind::BusinessCenter, 1)]
+            .into_iter()
+            .collect();
+        let mut game = Game::new(players, get_strategies(2), 0).expect("Config to be valid.");
+        let subscriber_events = Rc::clone(&events);
+        game.subscribe(Box::new(move |event: &GameEvent, _: &[Player]| {
+            subscriber_events.borrow_mut().push(event.clone())
+        }));
+
+        game.trade_establishments(0);
+        assert_eq!(
+            *events.borrow(),
+            vec![GameEvent::TradeUnavailable { player: 0 }]
+        );
+        assert!(game.record.decisions.is_empty());
+    }
+
+    #[test]
+    fn test_trade_skips_players_with_only_major_establishments() {
+        let mut players = get_players(3, &GameConfig::default());
+        players[1].cards = [CardStack::new(CardKind::Stadium, 1)].into_iter().collect();
+        for seed in 0..20 {
+            let mut game =
+                Game::new(players.clone(), get_strategies(3), seed).expect("Config to be valid.");
+            game.trade_establishments(0);
+            assert!(matches!(
+                game.record.decisions.as_slice(),
+                [Decision::Trade {
+                    other_player: 2,
+                    ..
+                }]
+            ));
+        }
+    }
+
+    // Each player's coins after the events, from the coins they started with.
+    fn get_coins_from_events(events: &[GameEvent], players: &[Player]) -> Vec<i32> {
+        let mut coins: Vec<i32> = players
+            .iter()
+            .map(|player| i32::from(player.coins))
+            .collect();
+        for event in events.iter() {
+            match event {
+                GameEvent::CoinsTransferred {
+                    from, to, amount, ..
+                } => {
+                    if let Some(from) = from {
+                        coins[*from] -= i32::from(*amount);
+                    }
+                    if let Some(to) = to {
+                        coins[*to] += i32::from(*amount);
+                    }
+                }
+                GameEvent::CardBought { player, cost, .. }
+                | GameEvent::LandmarkBuilt { player, cost, .. } => {
+                    coins[*player] -= i32::from(*cost)
+                }
+                #[cfg(feature = "millionaires-row")]
+                GameEvent::CoinInvested { player, .. } => coins[*player] -= 1,
+                _ => {}
+            }
+        }
+        coins
+    }
+
+    // Tries to build the Train Station every turn, whether or not it has been built already.
+    struct TrainStationBuilder;
+
+    impl PlayerStrategy for TrainStationBuilder {
+        fn get_id(&self) -> &'static str {
+            "train-station-builder"
+        }
+
+        fn get_kind_name(&self) -> &'static str {
+            "Computer"
+        }
+
+        fn get_roll_two_dice(&mut self, _view: &GameView, _rng: &mut GameRng) -> bool {
+            false
+        }
+
+        fn ask_reroll(&mut self, _view: &GameView, _roll: DiceRoll, _rng: &mut GameRng) -> bool {
+            false
+        }
+
+        #[cfg(feature = "harbor")]
+        fn ask_add_two(&mut self, _view: &GameView, _roll_total: u8, _rng: &mut GameRng) -> bool {
+            false
+        }
+
+        fn get_trade_establishments(
+            &mut self,
+            _view: &GameView,
+            _owner_turn: usize,
+            _rng: &mut GameRng,
+        ) -> Option<(usize, CardKind, CardKind)> {
+            unreachable!("Never owns a Business Center.")
+        }
+
+        fn get_player_to_steal_coins_from(
+            &mut self,
+            _view: &GameView,
+            _owner_turn: usize,
+            _amount: u8,
+            _rng: &mut GameRng,
+        ) -> usize {
+            unreachable!("Never owns a TV Station.")
+        }
+
+        fn buy_a_card(&mut self, _view: &GameView, _rng: &mut GameRng) -> Purchase {
+            Purchase::Landmark(LandmarkKind::TrainStation)
+        }
+
+        #[cfg(feature = "millionaires-row")]
+        fn ask_invest(&mut self, _view: &GameView, _rng: &mut GameRng) -> bool {
+            unreachable!("Never owns a Tech Startup.")
+        }
+
+        #[cfg(feature = "millionaires-row")]
+        fn get_establishment_to_give(
+            &mut self,
+            _view: &GameView,
+            _owner_turn: usize,
+            _rng: &mut GameRng,
+        ) -> (usize, CardKind) {
+            unreachable!("Never owns a Moving Company.")
+        }
+
+        #[cfg(feature = "millionaires-row")]
+        fn get_landmark_to_demolish(
+            &mut self,
+            _view: &GameView,
+            _owner_turn: usize,
+            _rng: &mut GameRng,
+        ) -> LandmarkKind {
+            unreachable!("Never owns a Demolition Company.")
+        }
+
+        #[cfg(feature = "millionaires-row")]
+        fn get_establishment_to_renovate(
+            &mut self,
+            _view: &GameView,
+            _owner_turn: usize,
+            _rng: &mut GameRng,
+        ) -> CardKind {
+            unreachable!("Never owns a Renovation Company.")
+        }
+
+        #[cfg(feature = "millionaires-row")]
+        fn get_establishment_to_exhibit(
+            &mut self,
+            _view: &GameView,
+            _owner_turn: usize,
+            _rng: &mut GameRng,
+        ) -> Option<CardKind> {
+            unreachable!("Never owns an Exhibit Hall.")
+        }
+    }
+}
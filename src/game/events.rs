@@ -0,0 +1,147 @@
+use super::{
+    cards::CardKind,
+    controller::{DiceRoll, GameError},
+    landmarks::LandmarkKind,
+    player::Player,
+};
+
+// Everything that happens during a game, in the order it happens. Players are referred to by
+// turn and the bank is represented by None.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    TurnStarted {
+        player: usize,
+    },
+    DiceRolled {
+        player: usize,
+        roll: DiceRoll,
+        total: u8,
+    },
+    Rerolled {
+        player: usize,
+        roll: DiceRoll,
+        total: u8,
+    },
+    // The player added 2 to the dice total with the Harbor.
+    #[cfg(feature = "harbor")]
+    HarborUsed {
+        player: usize,
+        total: u8,
+    },
+    // The player rolled again for a card that pays out the dice total, such as the Tuna Boat.
+    #[cfg(feature = "harbor")]
+    BonusDiceRolled {
+        player: usize,
+        card: CardKind,
+        roll: DiceRoll,
+        total: u8,
+    },
+    CardActivated {
+        owner: usize,
+        card: CardKind,
+        count: u8,
+    },
+    LandmarkActivated {
+        player: usize,
+        landmark: LandmarkKind,
+    },
+    // The owner's establishments were closed for renovation.
+    #[cfg(feature = "millionaires-row")]
+    EstablishmentsClosed {
+        player: usize,
+        card: CardKind,
+    },
+    // Closed establishments reopened instead of activating.
+    #[cfg(feature = "millionaires-row")]
+    EstablishmentsReopened {
+        player: usize,
+        card: CardKind,
+    },
+    // The card activated but there was nothing it could do.
+    #[cfg(feature = "millionaires-row")]
+    CardHadNoEffect {
+        player: usize,
+        card: CardKind,
+    },
+    CoinsTransferred {
+        from: Option<usize>,
+        to: Option<usize>,
+        amount: u8,
+        card: Option<CardKind>,
+    },
+    EstablishmentsTraded {
+        player: usize,
+        other_player: usize,
+        player_card: CardKind,
+        other_player_card: CardKind,
+    },
+    #[cfg(feature = "millionaires-row")]
+    EstablishmentGiven {
+        player: usize,
+        other_player: usize,
+        card: CardKind,
+    },
+    // The establishment went back to the supply.
+    #[cfg(feature = "millionaires-row")]
+    EstablishmentReturned {
+        player: usize,
+        card: CardKind,
+    },
+    #[cfg(feature = "millionaires-row")]
+    LandmarkDemolished {
+        player: usize,
+        landmark: LandmarkKind,
+    },
+    // The player moved 1 coin onto the card.
+    #[cfg(feature = "millionaires-row")]
+    CoinInvested {
+        player: usize,
+        card: CardKind,
+    },
+    // The Business Center activated but its owner had nothing to trade, or nobody to trade with.
+    TradeUnavailable {
+        player: usize,
+    },
+    TradeDeclined {
+        player: usize,
+    },
+    CardBought {
+        player: usize,
+        card: CardKind,
+        cost: u8,
+    },
+    LandmarkBuilt {
+        player: usize,
+        landmark: LandmarkKind,
+        cost: u8,
+    },
+    PurchaseSkipped {
+        player: usize,
+    },
+    // A card was drawn from the marketplace and put face up.
+    CardRevealed {
+        card: CardKind,
+    },
+    // A decision that broke the rules and was ignored.
+    ActionRejected {
+        player: usize,
+        error: GameError,
+    },
+    ExtraTurn {
+        player: usize,
+    },
+    GameWon {
+        player: usize,
+    },
+}
+
+// Receives every event emitted by a game, along with the state of the players after it happened.
+pub trait GameEventSubscriber {
+    fn on_event(&mut self, event: &GameEvent, players: &[Player]);
+}
+
+impl<F: FnMut(&GameEvent, &[Player])> GameEventSubscriber for F {
+    fn on_event(&mut self, event: &GameEvent, players: &[Player]) {
+        self(event, players)
+    }
+}
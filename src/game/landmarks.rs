@@ -1,18 +1,33 @@
-pub const LANDMARK_KIND_COUNT: usize = 4;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, PartialEq)]
+use super::config::Expansion;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum LandmarkKind {
     TrainStation,
     ShoppingMall,
     AmusementPark,
     RadioTower,
+    #[cfg(feature = "harbor")]
+    CityHall,
+    #[cfg(feature = "harbor")]
+    Harbor,
+    #[cfg(feature = "harbor")]
+    Airport,
 }
 
-pub const ALL_LANDMARKS: [LandmarkKind; LANDMARK_KIND_COUNT] = [
+// Every landmark compiled in, in the order they are built.
+pub const ALL_LANDMARKS: &[LandmarkKind] = &[
+    #[cfg(feature = "harbor")]
+    LandmarkKind::CityHall,
+    #[cfg(feature = "harbor")]
+    LandmarkKind::Harbor,
     LandmarkKind::TrainStation,
     LandmarkKind::ShoppingMall,
     LandmarkKind::AmusementPark,
     LandmarkKind::RadioTower,
+    #[cfg(feature = "harbor")]
+    LandmarkKind::Airport,
 ];
 
 pub fn get_landmark_cost(landmark: &LandmarkKind) -> u8 {
@@ -21,6 +36,12 @@ pub fn get_landmark_cost(landmark: &LandmarkKind) -> u8 {
         LandmarkKind::ShoppingMall => 10,
         LandmarkKind::AmusementPark => 16,
         LandmarkKind::RadioTower => 22,
+        #[cfg(feature = "harbor")]
+        LandmarkKind::CityHall => 0,
+        #[cfg(feature = "harbor")]
+        LandmarkKind::Harbor => 2,
+        #[cfg(feature = "harbor")]
+        LandmarkKind::Airport => 30,
     }
 }
 
@@ -30,6 +51,12 @@ pub fn get_landmark_title(landmark: &LandmarkKind) -> &'static str {
         LandmarkKind::ShoppingMall => "Shopping Mall",
         LandmarkKind::AmusementPark => "Amusement Park",
         LandmarkKind::RadioTower => "Radio Tower",
+        #[cfg(feature = "harbor")]
+        LandmarkKind::CityHall => "City Hall",
+        #[cfg(feature = "harbor")]
+        LandmarkKind::Harbor => "Harbor",
+        #[cfg(feature = "harbor")]
+        LandmarkKind::Airport => "Airport",
     }
 }
 
@@ -39,5 +66,41 @@ pub fn get_landmark_description(landmark: &LandmarkKind) -> &'static str {
         LandmarkKind::ShoppingMall => "Each of your Cup and Bread establishments earn +1 coin.",
         LandmarkKind::AmusementPark => "If you roll doubles, take another turn after this one.",
         LandmarkKind::RadioTower => "Once every turn, you can choose to re-roll your dice.",
+        #[cfg(feature = "harbor")]
+        LandmarkKind::CityHall => {
+            "Immediately before buying establishments, if you have 0 coins, get 1 from the bank."
+        }
+        #[cfg(feature = "harbor")]
+        LandmarkKind::Harbor => "If the dice total is 10 or more, you may add 2 to the total.",
+        #[cfg(feature = "harbor")]
+        LandmarkKind::Airport => "If you build nothing on your turn, get 10 coins from the bank.",
+    }
+}
+
+// The expansion the landmark comes from, or None for the base game.
+pub fn get_landmark_expansion(landmark: &LandmarkKind) -> Option<Expansion> {
+    match landmark {
+        LandmarkKind::TrainStation
+        | LandmarkKind::ShoppingMall
+        | LandmarkKind::AmusementPark
+        | LandmarkKind::RadioTower => None,
+        #[cfg(feature = "harbor")]
+        LandmarkKind::CityHall | LandmarkKind::Harbor | LandmarkKind::Airport => {
+            Some(Expansion::Harbor)
+        }
+    }
+}
+
+// Whether every player starts the game with the landmark already built.
+pub fn is_landmark_built_at_start(landmark: &LandmarkKind) -> bool {
+    match landmark {
+        LandmarkKind::TrainStation
+        | LandmarkKind::ShoppingMall
+        | LandmarkKind::AmusementPark
+        | LandmarkKind::RadioTower => false,
+        #[cfg(feature = "harbor")]
+        LandmarkKind::CityHall => true,
+        #[cfg(feature = "harbor")]
+        LandmarkKind::Harbor | LandmarkKind::Airport => false,
     }
 }
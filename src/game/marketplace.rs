@@ -0,0 +1,138 @@
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use super::{cards::CardKind, controller::GameRng, deck::Deck};
+
+// The Harbor supply: every establishment is shuffled into a draw pile and only the piles
+// revealed from it can be bought. The face-up piles are the card counts in the game's deck.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Marketplace {
+    // How many distinct piles are kept face up.
+    pub pile_count: usize,
+    // The face-down cards, drawn from the end.
+    draw_pile: Vec<CardKind>,
+}
+
+impl Marketplace {
+    // Moves every card in the deck into a shuffled draw pile, then reveals the first piles.
+    pub fn from_deck(card_deck: &mut Deck, pile_count: usize, rng: &mut GameRng) -> Self {
+        let mut draw_pile: Vec<CardKind> = card_deck
+            .iter()
+            .flat_map(|card| std::iter::repeat_n(card.kind, usize::from(card.count)))
+            .collect();
+        draw_pile.shuffle(rng);
+        for card in card_deck.iter_mut() {
+            card.count = 0;
+        }
+
+        let mut marketplace = Marketplace {
+            pile_count,
+            draw_pile,
+        };
+        marketplace.refill(card_deck);
+        marketplace
+    }
+
+    // Draws cards until there are enough distinct piles face up or the draw pile runs out.
+    // Cards of a kind that is already face up go onto its pile. Returns the cards drawn.
+    pub fn refill(&mut self, card_deck: &mut Deck) -> Vec<CardKind> {
+        let mut drawn = Vec::new();
+        while count_face_up_piles(card_deck) < self.pile_count {
+            let card_kind = match self.draw_pile.pop() {
+                Some(card_kind) => card_kind,
+                None => break,
+            };
+            card_deck.get_or_insert_mut(card_kind).count += 1;
+            drawn.push(card_kind);
+        }
+        drawn
+    }
+
+    pub fn get_draw_pile_count(&self) -> usize {
+        self.draw_pile.len()
+    }
+
+    pub fn get_draw_pile(&self) -> &[CardKind] {
+        &self.draw_pile
+    }
+}
+
+pub fn count_face_up_piles(card_deck: &Deck) -> usize {
+    card_deck.iter().filter(|card| card.count > 0).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use crate::game::{
+        config::{GameConfig, SupplyMode},
+        controller::{create_deck, GameRng},
+        engine::Game,
+        test_helpers::{get_players, get_strategies},
+    };
+
+    use super::{count_face_up_piles, Marketplace};
+
+    #[test]
+    fn test_marketplace_reveals_piles() {
+        let config = GameConfig::default();
+        let total_cards = create_deck(&config).count_cards();
+        let mut card_deck = create_deck(&config);
+        let marketplace =
+            Marketplace::from_deck(&mut card_deck, 10, &mut GameRng::seed_from_u64(0));
+
+        assert_eq!(count_face_up_piles(&card_deck), 10);
+        assert_eq!(
+            card_deck.count_cards() + marketplace.get_draw_pile_count(),
+            total_cards
+        );
+    }
+
+    #[test]
+    fn test_marketplace_refills_empty_pile() {
+        let mut card_deck = create_deck(&GameConfig::default());
+        let mut marketplace =
+            Marketplace::from_deck(&mut card_deck, 10, &mut GameRng::seed_from_u64(1));
+        let card = card_deck
+            .iter_mut()
+            .find(|card| card.count > 0)
+            .expect("A pile to be face up.");
+        card.count = 0;
+        assert_eq!(count_face_up_piles(&card_deck), 9);
+
+        let drawn = marketplace.refill(&mut card_deck);
+        assert!(!drawn.is_empty());
+        assert_eq!(count_face_up_piles(&card_deck), 10);
+    }
+
+    #[test]
+    fn test_marketplace_runs_out() {
+        let mut card_deck = create_deck(&GameConfig::default());
+        let mut marketplace =
+            Marketplace::from_deck(&mut card_deck, 100, &mut GameRng::seed_from_u64(2));
+        assert_eq!(marketplace.get_draw_pile_count(), 0);
+        assert_eq!(count_face_up_piles(&card_deck), 15);
+        assert!(marketplace.refill(&mut card_deck).is_empty());
+    }
+
+    #[test]
+    fn test_marketplace_game() {
+        let config = GameConfig {
+            supply_mode: SupplyMode::Marketplace,
+            ..GameConfig::default()
+        };
+        let mut game = Game::with_config(get_players(3, &config), get_strategies(3), 3, config)
+            .expect("Config to be valid.");
+        for _ in 0..30 {
+            game.play_turn().expect("Turn to be legal.");
+            let marketplace = game
+                .marketplace
+                .as_ref()
+                .expect("Game to have a marketplace.");
+            assert!(
+                count_face_up_piles(&game.deck) == 10 || marketplace.get_draw_pile_count() == 0
+            );
+        }
+    }
+}
@@ -1,5 +1,19 @@
+pub mod analysis;
 pub mod cards;
+pub mod cli;
+pub mod config;
 pub mod controller;
+pub mod deck;
+pub mod engine;
+pub mod events;
 pub mod landmarks;
+pub mod marketplace;
 pub mod player;
 pub mod players;
+pub mod registry;
+pub mod replay;
+pub mod save;
+pub mod simulation;
+#[cfg(test)]
+mod test_helpers;
+pub mod tournament;
@@ -1,20 +1,11 @@
 use super::{
-    cards::CardKind,
-    controller::Deck,
-    landmarks::LandmarkKind,
-    players::{human, random},
+    config::GameConfig, controller::create_player_deck, deck::Deck, landmarks::LandmarkKind,
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
-pub enum PlayerKind {
-    RandomAI,
-    Human,
-}
-
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Player {
     pub name: String,
-    pub kind: PlayerKind,
     pub turn: usize,
     pub cards: Deck,
     pub coins: u8,
@@ -22,53 +13,14 @@ pub struct Player {
 }
 
 impl Player {
-    pub fn get_kind_name(&self) -> &'static str {
-        match self.kind {
-            PlayerKind::RandomAI => "Computer",
-            PlayerKind::Human => "Human",
-        }
-    }
-
-    pub fn get_roll_two_dice(&self) -> bool {
-        match self.kind {
-            PlayerKind::RandomAI => random::get_roll_two_dice(),
-            PlayerKind::Human => human::get_roll_two_dice(),
-        }
-    }
-    pub fn ask_reroll(&self) -> bool {
-        match self.kind {
-            PlayerKind::RandomAI => random::ask_reroll(),
-            PlayerKind::Human => human::ask_reroll(),
-        }
-    }
-
-    pub fn get_trade_establishments(
-        &self,
-        players: &Vec<Player>,
-        owner_turn: usize,
-    ) -> (usize, CardKind, CardKind) {
-        match self.kind {
-            PlayerKind::RandomAI => random::get_trade_establishments(players, owner_turn, self),
-            PlayerKind::Human => human::get_trade_establishments(players, owner_turn),
-        }
-    }
-
-    pub fn get_player_to_steal_coins_from(
-        &self,
-        players: &Vec<Player>,
-        owner_turn: usize,
-        amount: u8,
-    ) -> usize {
-        match self.kind {
-            PlayerKind::RandomAI => random::get_player_to_steal_coins_from(players, owner_turn),
-            PlayerKind::Human => human::get_player_to_steal_coins_from(players, owner_turn, amount),
-        }
-    }
-
-    pub fn buy_a_card(&self, card_deck: &Deck) -> Option<(Option<CardKind>, Option<LandmarkKind>)> {
-        match self.kind {
-            PlayerKind::RandomAI => random::buy_a_card(self, card_deck),
-            PlayerKind::Human => human::buy_a_card(self, card_deck),
+    // A player with the cards, coins and landmarks that every player starts the game with.
+    pub fn new(turn: usize, name: String, config: &GameConfig) -> Self {
+        Player {
+            name,
+            turn,
+            cards: create_player_deck(config),
+            coins: config.starting_coins,
+            landmarks: config.get_starting_landmarks(),
         }
     }
 }
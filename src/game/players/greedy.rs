@@ -0,0 +1,369 @@
+#[cfg(feature = "millionaires-row")]
+use crate::game::{
+    analysis::get_card_payout,
+    cards::CardStack,
+    controller::{
+        get_legal_demolitions, get_legal_exhibits, get_legal_gifts, get_legal_renovations,
+        get_legal_roll_two_dice,
+    },
+    landmarks::LandmarkKind,
+};
+use crate::game::{
+    analysis::{
+        get_best_roll_two_dice, get_expected_income, get_expected_roll_outcome, get_income_gain,
+        get_roll_outcome,
+    },
+    cards::CardKind,
+    controller::{
+        count_built_landmarks, get_legal_purchases, get_legal_steal_targets, get_legal_trades,
+        DiceRoll, GameRng, Purchase,
+    },
+    player::Player,
+    registry::get_card_definition,
+};
+
+use super::{GameView, PlayerStrategy};
+
+// The most rounds an establishment may take to pay for itself and still be worth buying. Coins
+// that would go into slower establishments are saved for landmarks instead.
+const PAYBACK_ROUNDS: f64 = 8.0;
+
+// A computer player that buys whatever raises its expected income the most, given the chance of
+// rolling each total and the establishments every player owns.
+pub struct GreedyAI;
+
+impl PlayerStrategy for GreedyAI {
+    fn get_id(&self) -> &'static str {
+        "greedy"
+    }
+
+    fn get_kind_name(&self) -> &'static str {
+        "Greedy Computer"
+    }
+
+    fn get_roll_two_dice(&mut self, view: &GameView, _rng: &mut GameRng) -> bool {
+        get_best_roll_two_dice(view.players, view.current_turn)
+    }
+
+    fn ask_reroll(&mut self, view: &GameView, roll: DiceRoll, _rng: &mut GameRng) -> bool {
+        // Reroll when the roll pays less than rolling again is expected to.
+        let turn = view.current_turn;
+        let total = roll.0 + roll.1.unwrap_or_default();
+        get_roll_outcome(view.players, turn, turn, total)
+            < get_expected_roll_outcome(view.players, turn, turn, roll.1.is_some())
+    }
+
+    #[cfg(feature = "harbor")]
+    fn ask_add_two(&mut self, view: &GameView, roll_total: u8, _rng: &mut GameRng) -> bool {
+        let turn = view.current_turn;
+        get_roll_outcome(view.players, turn, turn, roll_total + 2)
+            > get_roll_outcome(view.players, turn, turn, roll_total)
+    }
+
+    fn get_trade_establishments(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> Option<(usize, CardKind, CardKind)> {
+        // Make the trade that raises the owner's income the most, if any does.
+        let income = get_expected_income(view.players, owner_turn);
+        get_legal_trades(view.players, owner_turn)
+            .into_iter()
+            .map(|trade| {
+                let (other_turn, other_card, owner_card) = trade;
+                let mut players = view.players.to_vec();
+                move_card(&mut players, other_turn, owner_turn, other_card);
+                move_card(&mut players, owner_turn, other_turn, owner_card);
+                (trade, get_expected_income(&players, owner_turn) - income)
+            })
+            .filter(|(_, gain)| *gain > 0.0)
+            .max_by(|(_, gain), (_, other_gain)| gain.total_cmp(other_gain))
+            .map(|(trade, _)| trade)
+    }
+
+    fn get_player_to_steal_coins_from(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _amount: u8,
+        _rng: &mut GameRng,
+    ) -> usize {
+        // Take from the leader, skipping players with nothing to take.
+        get_legal_steal_targets(view.players, owner_turn)
+            .into_iter()
+            .max_by_key(|turn| {
+                let player = view.get_player(*turn);
+                (
+                    player.coins > 0,
+                    count_built_landmarks(player),
+                    player.coins,
+                )
+            })
+            .expect("Player to not be None.")
+    }
+
+    fn buy_a_card(&mut self, view: &GameView, _rng: &mut GameRng) -> Purchase {
+        let turn = view.current_turn;
+        let purchases = get_legal_purchases(view.get_current_player(), view.deck, view.config);
+        let income = get_expected_income(view.players, turn);
+
+        // Landmarks win the game, so build one whenever possible. Prefer the one that raises
+        // income the most, then the most expensive.
+        let landmark = purchases
+            .iter()
+            .filter_map(|purchase| match purchase {
+                Purchase::Landmark(landmark_kind) => Some(landmark_kind),
+                _ => None,
+            })
+            .map(|landmark_kind| {
+                let mut players = view.players.to_vec();
+                players[turn].landmarks.push(landmark_kind.clone());
+                let gain = get_expected_income(&players, turn) - income;
+                (
+                    landmark_kind,
+                    gain,
+                    view.config.get_landmark_cost(landmark_kind),
+                )
+            })
+            .max_by(|(_, gain, cost), (_, other_gain, other_cost)| {
+                gain.total_cmp(other_gain).then(cost.cmp(other_cost))
+            });
+        if let Some((landmark_kind, _, _)) = landmark {
+            return Purchase::Landmark(landmark_kind.clone());
+        }
+
+        // Otherwise buy the establishment that raises income the most, as long as it pays for
+        // itself soon enough. Prefer the cheaper of equally good establishments.
+        purchases
+            .iter()
+            .filter_map(|purchase| match purchase {
+                Purchase::Card(card_kind) => Some(*card_kind),
+                _ => None,
+            })
+            .map(|card_kind| {
+                let gain = get_income_gain(view.players, turn, income, card_kind);
+                (card_kind, gain, get_card_definition(card_kind).cost)
+            })
+            .filter(|(_, gain, cost)| *gain > 0.0 && f64::from(*cost) <= gain * PAYBACK_ROUNDS)
+            .max_by(|(_, gain, cost), (_, other_gain, other_cost)| {
+                gain.total_cmp(other_gain).then(other_cost.cmp(cost))
+            })
+            .map_or(Purchase::Pass, |(card_kind, _, _)| {
+                Purchase::Card(card_kind)
+            })
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn ask_invest(&mut self, view: &GameView, _rng: &mut GameRng) -> bool {
+        // The Tech Startup only activates on a 10, which takes two dice.
+        get_legal_roll_two_dice(view.get_current_player()).len() > 1
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_give(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> (usize, CardKind) {
+        // Give away whatever the owner earns the least from, to the player furthest behind.
+        get_legal_gifts(view.players, owner_turn)
+            .into_iter()
+            .map(|gift| {
+                let (other_turn, card_kind) = gift;
+                let mut players = view.players.to_vec();
+                move_card(&mut players, owner_turn, other_turn, card_kind);
+                let other_player = view.get_player(other_turn);
+                (
+                    gift,
+                    get_expected_income(&players, owner_turn),
+                    count_built_landmarks(other_player),
+                    other_player.coins,
+                )
+            })
+            .max_by(
+                |(_, income, landmarks, coins), (_, other_income, other_landmarks, other_coins)| {
+                    income
+                        .total_cmp(other_income)
+                        .then(other_landmarks.cmp(landmarks))
+                        .then(other_coins.cmp(coins))
+                },
+            )
+            .map(|(gift, _, _, _)| gift)
+            .expect("Owner to have an establishment to give.")
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_landmark_to_demolish(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> LandmarkKind {
+        // Demolish the landmark that is cheapest to build again.
+        get_legal_demolitions(view.get_player(owner_turn))
+            .into_iter()
+            .min_by_key(|landmark_kind| view.config.get_landmark_cost(landmark_kind))
+            .expect("Owner to have a landmark to demolish.")
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_renovate(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> CardKind {
+        // Close the kind that the other players own the most of compared to the owner.
+        get_legal_renovations(view.players)
+            .into_iter()
+            .max_by_key(|card_kind| {
+                view.players
+                    .iter()
+                    .map(|player| {
+                        let count = i32::from(player.cards.get_count(*card_kind));
+                        if player.turn == owner_turn {
+                            -count
+                        } else {
+                            count
+                        }
+                    })
+                    .sum::<i32>()
+            })
+            .expect("An establishment to renovate.")
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_exhibit(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> Option<CardKind> {
+        // Exhibit whatever pays the most right now.
+        get_legal_exhibits(view.get_player(owner_turn))
+            .into_iter()
+            .map(|card_kind| {
+                let card = CardStack::new(card_kind, 1);
+                let payout = get_card_payout(view.players, owner_turn, view.current_turn, &card);
+                (card_kind, payout)
+            })
+            .filter(|(_, payout)| *payout > 0.0)
+            .max_by(|(_, payout), (_, other_payout)| payout.total_cmp(other_payout))
+            .map(|(card_kind, _)| card_kind)
+    }
+}
+
+fn move_card(players: &mut [Player], from_turn: usize, to_turn: usize, card_kind: CardKind) {
+    players[from_turn].cards.get_or_insert_mut(card_kind).count -= 1;
+    players[to_turn].cards.get_or_insert_mut(card_kind).count += 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+
+    use crate::game::{
+        cards::CardKind,
+        config::GameConfig,
+        controller::{create_deck, GameRng, Purchase},
+        deck::Deck,
+        engine::Game,
+        landmarks::LandmarkKind,
+        player::Player,
+        players::{random::RandomAI, GameView, PlayerStrategy},
+        registry::get_card_definition,
+    };
+
+    use super::GreedyAI;
+    use crate::game::test_helpers::get_players;
+
+    #[test]
+    fn test_rolls_two_dice_for_high_establishments() {
+        let config = GameConfig::default();
+        let deck = create_deck(&config);
+        let mut players = get_players(3, &config);
+        players[0].landmarks.push(LandmarkKind::TrainStation);
+        let view = get_view(&players, &deck, &config);
+        let mut rng = GameRng::seed_from_u64(0);
+        assert!(!GreedyAI.get_roll_two_dice(&view, &mut rng));
+
+        players[0].cards.set_count(CardKind::Mine, 2);
+        let view = get_view(&players, &deck, &config);
+        assert!(GreedyAI.get_roll_two_dice(&view, &mut rng));
+    }
+
+    #[test]
+    fn test_rerolls_poor_rolls() {
+        let config = GameConfig::default();
+        let deck = create_deck(&config);
+        let players = get_players(3, &config);
+        let view = get_view(&players, &deck, &config);
+        let mut rng = GameRng::seed_from_u64(0);
+        assert!(!GreedyAI.ask_reroll(&view, (2, None), &mut rng));
+        assert!(GreedyAI.ask_reroll(&view, (6, None), &mut rng));
+    }
+
+    #[test]
+    fn test_steals_from_leader() {
+        let config = GameConfig::default();
+        let deck = create_deck(&config);
+        let mut players = get_players(3, &config);
+        players[1].coins = 8;
+        players[2].coins = 4;
+        players[2].landmarks.push(LandmarkKind::TrainStation);
+        let view = get_view(&players, &deck, &config);
+        let mut rng = GameRng::seed_from_u64(0);
+        assert_eq!(
+            GreedyAI.get_player_to_steal_coins_from(&view, 0, 5, &mut rng),
+            2
+        );
+    }
+
+    #[test]
+    fn test_buys_income() {
+        let config = GameConfig::default();
+        let mut players = get_players(3, &config);
+        players[0].coins = 3;
+        let deck = create_deck(&config);
+        let view = get_view(&players, &deck, &config);
+        let mut rng = GameRng::seed_from_u64(0);
+        // Establishments that need two dice are worthless without the Train Station.
+        match GreedyAI.buy_a_card(&view, &mut rng) {
+            Purchase::Card(card_kind) => assert!(get_card_definition(card_kind)
+                .activation
+                .iter()
+                .any(|roll| *roll <= 6)),
+            purchase => panic!("Expected an establishment, got {:?}.", purchase),
+        }
+
+        players[0].coins = 4;
+        let view = get_view(&players, &deck, &config);
+        assert_eq!(
+            GreedyAI.buy_a_card(&view, &mut rng),
+            Purchase::Landmark(LandmarkKind::TrainStation)
+        );
+    }
+
+    #[test]
+    fn test_greedy_game() {
+        let config = GameConfig::default();
+        let strategies: Vec<Box<dyn PlayerStrategy>> =
+            vec![Box::new(GreedyAI), Box::new(RandomAI), Box::new(GreedyAI)];
+        let mut game =
+            Game::new(get_players(3, &config), strategies, 8).expect("Config to be valid.");
+        game.run_to_completion().expect("Game to finish.");
+        assert!(game.winner.is_some());
+    }
+
+    fn get_view<'a>(players: &'a [Player], deck: &'a Deck, config: &'a GameConfig) -> GameView<'a> {
+        GameView {
+            players,
+            deck,
+            config,
+            current_turn: 0,
+            roll: None,
+        }
+    }
+}
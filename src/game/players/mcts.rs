@@ -0,0 +1,736 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use crate::game::{
+    analysis::{get_card_income, get_expected_income, get_income_gain},
+    cards::{CardKind, CardStack},
+    controller::{
+        get_legal_purchases, get_legal_roll_two_dice, get_legal_steal_targets, DiceRoll, GameRng,
+        Purchase,
+    },
+    engine::{Game, TurnOutcome},
+    registry::get_card_definition,
+};
+#[cfg(feature = "millionaires-row")]
+use crate::game::{
+    controller::{get_legal_demolitions, get_legal_gifts, get_legal_renovations},
+    landmarks::LandmarkKind,
+};
+use rand::Rng;
+
+use super::{greedy::GreedyAI, GameView, PlayerStrategy};
+
+pub const DEFAULT_ITERATIONS: u32 = 400;
+
+// Playouts that go on longer than this are counted as losses.
+const MAX_PLAYOUT_TURNS: usize = 1000;
+
+// How many of the establishments that raise expected income the most are searched.
+const SEARCHED_CARDS: usize = 3;
+
+// Balances trying the purchases with the best win rate so far against trying the others more.
+const EXPLORATION: f64 = std::f64::consts::SQRT_2;
+
+// How long the search may run for each purchase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchBudget {
+    Iterations(u32),
+    // Games searched for a time can play out differently when replayed with the same seed.
+    Time(Duration),
+}
+
+// A computer player that picks its purchases by Monte Carlo tree search. The tree holds the
+// player's own purchases: the ones it could make now, and under each of them the ones tried on
+// its following turns. Every iteration plays a copy of the game to the end, starting with the
+// rest of the current turn. Purchases already in the tree are picked with UCB1, one purchase is
+// added to the tree, and quick rollout players take over from there. The result is then counted
+// for every purchase on the way down, and the purchase with the best win rate is bought. Dice
+// differ between playouts, so a purchase only competes with the others in the playouts where it
+// could be made. Every other decision is made like the greedy computer.
+pub struct MctsAI {
+    pub budget: SearchBudget,
+}
+
+impl MctsAI {
+    pub fn new(budget: SearchBudget) -> Self {
+        MctsAI { budget }
+    }
+
+    // Searches the purchases the current player could make, returning them with their playouts.
+    fn search(&self, view: &GameView, rng: &mut GameRng) -> Vec<Node> {
+        let turn = view.current_turn;
+        let income = get_expected_income(view.players, turn);
+        let search = Rc::new(RefCell::new(Search {
+            children: get_searched_purchases(view, |card_kind| {
+                get_income_gain(view.players, turn, income, card_kind)
+            })
+            .into_iter()
+            .map(Node::new)
+            .collect(),
+            path: Vec::new(),
+            pending: None,
+            left_tree: false,
+        }));
+        if search.borrow().children.len() == 1 {
+            return search.take().children;
+        }
+
+        // The nth playout of every purchase rolls the same dice, so that the purchases are compared
+        // on the same luck rather than on whichever got the better rolls.
+        let seed: u64 = rng.gen();
+        let start = Instant::now();
+        let mut total_playouts = 0;
+        while match self.budget {
+            SearchBudget::Iterations(iterations) => total_playouts < iterations,
+            SearchBudget::Time(duration) => start.elapsed() < duration,
+        } {
+            let playout_seed = {
+                let mut search = search.borrow_mut();
+                let purchases: Vec<Purchase> = search
+                    .children
+                    .iter()
+                    .map(|node| node.purchase.clone())
+                    .collect();
+                let index = select_child(&search.children, &purchases);
+                search.path = vec![index];
+                search.pending = Some(purchases[index].clone());
+                search.left_tree = false;
+                seed.wrapping_add(u64::from(search.children[index].playouts))
+            };
+            let won = play_out(view, &search, playout_seed);
+            search.borrow_mut().backpropagate(won);
+            total_playouts += 1;
+        }
+        search.take().children
+    }
+}
+
+impl Default for MctsAI {
+    fn default() -> Self {
+        MctsAI::new(SearchBudget::Iterations(DEFAULT_ITERATIONS))
+    }
+}
+
+// A purchase in the search tree and the playouts that made it.
+struct Node {
+    purchase: Purchase,
+    playouts: u32,
+    wins: u32,
+    // The purchases tried on the player's next turn after this one.
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn new(purchase: Purchase) -> Self {
+        Node {
+            purchase,
+            playouts: 0,
+            wins: 0,
+            children: Vec::new(),
+        }
+    }
+
+    fn get_win_rate(&self) -> f64 {
+        if self.playouts == 0 {
+            0.0
+        } else {
+            f64::from(self.wins) / f64::from(self.playouts)
+        }
+    }
+
+    fn get_upper_bound(&self, total_playouts: u32) -> f64 {
+        if self.playouts == 0 {
+            return f64::INFINITY;
+        }
+        self.get_win_rate()
+            + EXPLORATION * (f64::from(total_playouts).ln() / f64::from(self.playouts)).sqrt()
+    }
+}
+
+// The search tree, shared with the searching player's seat in the playouts.
+#[derive(Default)]
+struct Search {
+    // The purchases that can be made now.
+    children: Vec<Node>,
+    // The index of each purchase made in the current playout, from the top of the tree down.
+    path: Vec<usize>,
+    // The purchase to make for the rest of the current turn, picked before the playout starts.
+    pending: Option<Purchase>,
+    // Whether the current playout has added its purchase to the tree. Later purchases are made
+    // by the rollout player.
+    left_tree: bool,
+}
+
+impl Search {
+    // The purchases tried after the last one made in the current playout.
+    fn get_children_mut(&mut self) -> &mut Vec<Node> {
+        let mut children = &mut self.children;
+        for &index in &self.path {
+            children = &mut children[index].children;
+        }
+        children
+    }
+
+    // Counts the result of the current playout for every purchase made in it.
+    fn backpropagate(&mut self, won: bool) {
+        let mut children = &mut self.children;
+        for &index in &self.path {
+            let node = &mut children[index];
+            node.playouts += 1;
+            if won {
+                node.wins += 1;
+            }
+            children = &mut node.children;
+        }
+    }
+}
+
+impl PlayerStrategy for MctsAI {
+    fn get_id(&self) -> &'static str {
+        "mcts"
+    }
+
+    fn get_kind_name(&self) -> &'static str {
+        "MCTS Computer"
+    }
+
+    fn get_roll_two_dice(&mut self, view: &GameView, rng: &mut GameRng) -> bool {
+        GreedyAI.get_roll_two_dice(view, rng)
+    }
+
+    fn ask_reroll(&mut self, view: &GameView, roll: DiceRoll, rng: &mut GameRng) -> bool {
+        GreedyAI.ask_reroll(view, roll, rng)
+    }
+
+    #[cfg(feature = "harbor")]
+    fn ask_add_two(&mut self, view: &GameView, roll_total: u8, rng: &mut GameRng) -> bool {
+        GreedyAI.ask_add_two(view, roll_total, rng)
+    }
+
+    fn get_trade_establishments(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> Option<(usize, CardKind, CardKind)> {
+        GreedyAI.get_trade_establishments(view, owner_turn, rng)
+    }
+
+    fn get_player_to_steal_coins_from(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        amount: u8,
+        rng: &mut GameRng,
+    ) -> usize {
+        GreedyAI.get_player_to_steal_coins_from(view, owner_turn, amount, rng)
+    }
+
+    fn buy_a_card(&mut self, view: &GameView, rng: &mut GameRng) -> Purchase {
+        // Prefer the earlier purchase, starting with passing, when win rates are tied.
+        self.search(view, rng)
+            .into_iter()
+            .rev()
+            .max_by(|node, other_node| node.get_win_rate().total_cmp(&other_node.get_win_rate()))
+            .map(|node| node.purchase)
+            .expect("A purchase to search.")
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn ask_invest(&mut self, view: &GameView, rng: &mut GameRng) -> bool {
+        GreedyAI.ask_invest(view, rng)
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_give(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> (usize, CardKind) {
+        GreedyAI.get_establishment_to_give(view, owner_turn, rng)
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_landmark_to_demolish(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> LandmarkKind {
+        GreedyAI.get_landmark_to_demolish(view, owner_turn, rng)
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_renovate(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> CardKind {
+        GreedyAI.get_establishment_to_renovate(view, owner_turn, rng)
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_exhibit(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> Option<CardKind> {
+        GreedyAI.get_establishment_to_exhibit(view, owner_turn, rng)
+    }
+}
+
+// The searching player's seat in a playout. Makes purchases from the search tree until the
+// playout leaves it, and otherwise plays like the rollout player.
+struct Planner {
+    search: Rc<RefCell<Search>>,
+}
+
+impl PlayerStrategy for Planner {
+    fn get_id(&self) -> &'static str {
+        "planner"
+    }
+
+    fn get_kind_name(&self) -> &'static str {
+        "Planner"
+    }
+
+    fn get_roll_two_dice(&mut self, view: &GameView, rng: &mut GameRng) -> bool {
+        Rollout.get_roll_two_dice(view, rng)
+    }
+
+    fn ask_reroll(&mut self, view: &GameView, roll: DiceRoll, rng: &mut GameRng) -> bool {
+        Rollout.ask_reroll(view, roll, rng)
+    }
+
+    #[cfg(feature = "harbor")]
+    fn ask_add_two(&mut self, view: &GameView, roll_total: u8, rng: &mut GameRng) -> bool {
+        Rollout.ask_add_two(view, roll_total, rng)
+    }
+
+    fn get_trade_establishments(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> Option<(usize, CardKind, CardKind)> {
+        Rollout.get_trade_establishments(view, owner_turn, rng)
+    }
+
+    fn get_player_to_steal_coins_from(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        amount: u8,
+        rng: &mut GameRng,
+    ) -> usize {
+        Rollout.get_player_to_steal_coins_from(view, owner_turn, amount, rng)
+    }
+
+    fn buy_a_card(&mut self, view: &GameView, rng: &mut GameRng) -> Purchase {
+        let mut search = self.search.borrow_mut();
+        if let Some(purchase) = search.pending.take() {
+            return purchase;
+        }
+        if search.left_tree {
+            return Rollout.buy_a_card(view, rng);
+        }
+
+        // Add the first purchase that isn't in the tree yet, or pick one of those that are. This
+        // is worked out in every playout, so the establishments are compared by the quicker
+        // estimate of the rollout player.
+        let purchases = get_searched_purchases(view, |card_kind| {
+            get_card_income(view.players, view.current_turn, card_kind)
+        });
+        let children = search.get_children_mut();
+        let (index, added) = match purchases
+            .iter()
+            .find(|purchase| !children.iter().any(|child| child.purchase == **purchase))
+        {
+            Some(purchase) => {
+                children.push(Node::new(purchase.clone()));
+                (children.len() - 1, true)
+            }
+            None => (select_child(children, &purchases), false),
+        };
+        let purchase = children[index].purchase.clone();
+        search.path.push(index);
+        search.left_tree = added;
+        purchase
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn ask_invest(&mut self, view: &GameView, rng: &mut GameRng) -> bool {
+        Rollout.ask_invest(view, rng)
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_give(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> (usize, CardKind) {
+        Rollout.get_establishment_to_give(view, owner_turn, rng)
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_landmark_to_demolish(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> LandmarkKind {
+        Rollout.get_landmark_to_demolish(view, owner_turn, rng)
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_renovate(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> CardKind {
+        Rollout.get_establishment_to_renovate(view, owner_turn, rng)
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_exhibit(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> Option<CardKind> {
+        Rollout.get_establishment_to_exhibit(view, owner_turn, rng)
+    }
+}
+
+// A quick player for playouts, which have to be played by the hundred for every purchase. Builds
+// landmarks as soon as it can and otherwise buys the establishment that earns the most on its own.
+struct Rollout;
+
+impl PlayerStrategy for Rollout {
+    fn get_id(&self) -> &'static str {
+        "rollout"
+    }
+
+    fn get_kind_name(&self) -> &'static str {
+        "Rollout"
+    }
+
+    fn get_roll_two_dice(&mut self, view: &GameView, _rng: &mut GameRng) -> bool {
+        // Roll two dice once most establishments need them.
+        let (high, low): (Vec<&CardStack>, Vec<&CardStack>) = view
+            .get_current_player()
+            .cards
+            .iter()
+            .filter(|card| card.count > 0)
+            .partition(|card| needs_two_dice(card.kind));
+        get_legal_roll_two_dice(view.get_current_player()).len() > 1 && high.len() > low.len()
+    }
+
+    fn ask_reroll(&mut self, _view: &GameView, _roll: DiceRoll, _rng: &mut GameRng) -> bool {
+        false
+    }
+
+    #[cfg(feature = "harbor")]
+    fn ask_add_two(&mut self, _view: &GameView, _roll_total: u8, _rng: &mut GameRng) -> bool {
+        false
+    }
+
+    fn get_trade_establishments(
+        &mut self,
+        _view: &GameView,
+        _owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> Option<(usize, CardKind, CardKind)> {
+        None
+    }
+
+    fn get_player_to_steal_coins_from(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _amount: u8,
+        _rng: &mut GameRng,
+    ) -> usize {
+        get_legal_steal_targets(view.players, owner_turn)
+            .into_iter()
+            .max_by_key(|turn| view.get_player(*turn).coins)
+            .expect("Player to not be None.")
+    }
+
+    fn buy_a_card(&mut self, view: &GameView, _rng: &mut GameRng) -> Purchase {
+        let purchases = get_legal_purchases(view.get_current_player(), view.deck, view.config);
+        let landmark = purchases
+            .iter()
+            .filter(|purchase| matches!(purchase, Purchase::Landmark(_)))
+            .min_by_key(|purchase| match purchase {
+                Purchase::Landmark(landmark_kind) => view.config.get_landmark_cost(landmark_kind),
+                _ => u8::MAX,
+            });
+        if let Some(landmark) = landmark {
+            return landmark.clone();
+        }
+
+        purchases
+            .iter()
+            .filter_map(|purchase| match purchase {
+                Purchase::Card(card_kind) => Some(*card_kind),
+                _ => None,
+            })
+            .map(|card_kind| {
+                let income = get_card_income(view.players, view.current_turn, card_kind);
+                (card_kind, income)
+            })
+            .filter(|(_, income)| *income > 0.0)
+            .max_by(|(_, income), (_, other_income)| income.total_cmp(other_income))
+            .map_or(Purchase::Pass, |(card_kind, _)| Purchase::Card(card_kind))
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn ask_invest(&mut self, _view: &GameView, _rng: &mut GameRng) -> bool {
+        false
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_give(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> (usize, CardKind) {
+        *get_legal_gifts(view.players, owner_turn)
+            .first()
+            .expect("Owner to have an establishment to give.")
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_landmark_to_demolish(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> LandmarkKind {
+        get_legal_demolitions(view.get_player(owner_turn))
+            .into_iter()
+            .next()
+            .expect("Owner to have a landmark to demolish.")
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_renovate(
+        &mut self,
+        view: &GameView,
+        _owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> CardKind {
+        *get_legal_renovations(view.players)
+            .first()
+            .expect("An establishment to renovate.")
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_exhibit(
+        &mut self,
+        _view: &GameView,
+        _owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> Option<CardKind> {
+        None
+    }
+}
+
+// Whether the establishment only activates on totals that take two dice to roll.
+fn needs_two_dice(card_kind: CardKind) -> bool {
+    get_card_definition(card_kind)
+        .activation
+        .iter()
+        .all(|roll| *roll > 6)
+}
+
+// The purchases worth searching: passing, the landmarks and the establishments that raise
+// expected income the most, by the given estimate.
+fn get_searched_purchases(view: &GameView, get_gain: impl Fn(CardKind) -> f64) -> Vec<Purchase> {
+    let mut cards: Vec<(CardKind, f64)> = Vec::new();
+    let mut purchases: Vec<Purchase> = Vec::new();
+    for purchase in get_legal_purchases(view.get_current_player(), view.deck, view.config) {
+        match purchase {
+            Purchase::Card(card_kind) => {
+                let gain = get_gain(card_kind);
+                if gain > 0.0 {
+                    cards.push((card_kind, gain));
+                }
+            }
+            purchase => purchases.push(purchase),
+        }
+    }
+    cards.sort_by(|(_, gain), (_, other_gain)| other_gain.total_cmp(gain));
+    purchases.extend(
+        cards
+            .into_iter()
+            .take(SEARCHED_CARDS)
+            .map(|(card_kind, _)| Purchase::Card(card_kind)),
+    );
+    purchases
+}
+
+// Picks the child to play out next with UCB1, among those whose purchase can be made.
+fn select_child(children: &[Node], purchases: &[Purchase]) -> usize {
+    let available = || {
+        children
+            .iter()
+            .enumerate()
+            .filter(|(_, child)| purchases.contains(&child.purchase))
+    };
+    let total_playouts = available().map(|(_, child)| child.playouts).sum();
+    available()
+        .max_by(|(_, child), (_, other_child)| {
+            child
+                .get_upper_bound(total_playouts)
+                .total_cmp(&other_child.get_upper_bound(total_playouts))
+        })
+        .map(|(index, _)| index)
+        .expect("A purchase to search.")
+}
+
+// Plays a copy of the game to the end, starting with the rest of the current turn, and returns
+// whether the searching player won. A playout that goes on too long or runs into an inconsistent
+// game state counts as a loss.
+fn play_out(view: &GameView, search: &Rc<RefCell<Search>>, seed: u64) -> bool {
+    let turn = view.current_turn;
+    let strategies = (0..view.players.len())
+        .map(|player_turn| -> Box<dyn PlayerStrategy> {
+            if player_turn == turn {
+                Box::new(Planner {
+                    search: Rc::clone(search),
+                })
+            } else {
+                Box::new(Rollout)
+            }
+        })
+        .collect();
+    let mut game = match Game::from_view(view, strategies, seed) {
+        Ok(game) => game,
+        Err(_) => return false,
+    };
+    let mut outcome = game.finish_turn();
+    for _ in 0..MAX_PLAYOUT_TURNS {
+        match outcome {
+            Ok(TurnOutcome::Won) => return game.winner == Some(turn),
+            Ok(_) => outcome = game.play_turn(),
+            Err(_) => return false,
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use std::time::Duration;
+
+    use crate::game::{
+        config::GameConfig,
+        controller::{create_deck, get_legal_purchases, GameRng, Purchase},
+        engine::Game,
+        landmarks::LandmarkKind,
+        players::{random::RandomAI, GameView, PlayerStrategy},
+    };
+
+    use super::{MctsAI, Node, SearchBudget};
+    use crate::game::test_helpers::get_players;
+
+    #[test]
+    fn test_builds_winning_landmark() {
+        let config = GameConfig::default();
+        let mut players = get_players(2, &config);
+        players[0].landmarks = vec![
+            LandmarkKind::TrainStation,
+            LandmarkKind::ShoppingMall,
+            LandmarkKind::AmusementPark,
+        ];
+        players[0].coins = 22;
+        // The other player wins on their next turn unless this one wins first.
+        players[1].landmarks = players[0].landmarks.clone();
+        players[1].coins = 22;
+        let card_deck = create_deck(&config);
+        let view = GameView {
+            players: &players,
+            deck: &card_deck,
+            config: &config,
+            current_turn: 0,
+            roll: None,
+        };
+        let mut strategy = MctsAI::new(SearchBudget::Iterations(100));
+        assert_eq!(
+            strategy.buy_a_card(&view, &mut GameRng::seed_from_u64(0)),
+            Purchase::Landmark(LandmarkKind::RadioTower)
+        );
+    }
+
+    #[test]
+    fn test_search_grows_a_tree() {
+        let config = GameConfig::default();
+        let mut players = get_players(2, &config);
+        players[0].coins = 6;
+        let card_deck = create_deck(&config);
+        let view = GameView {
+            players: &players,
+            deck: &card_deck,
+            config: &config,
+            current_turn: 0,
+            roll: None,
+        };
+        let strategy = MctsAI::new(SearchBudget::Iterations(200));
+        let children = strategy.search(&view, &mut GameRng::seed_from_u64(0));
+        assert_eq!(children.iter().map(|node| node.playouts).sum::<u32>(), 200);
+        // Later purchases are searched too.
+        assert!(children.iter().any(|node| !node.children.is_empty()));
+        children.iter().for_each(assert_playouts_add_up);
+    }
+
+    #[test]
+    fn test_time_budget() {
+        let config = GameConfig::default();
+        let players = get_players(2, &config);
+        let card_deck = create_deck(&config);
+        let view = GameView {
+            players: &players,
+            deck: &card_deck,
+            config: &config,
+            current_turn: 0,
+            roll: None,
+        };
+        let mut strategy = MctsAI::new(SearchBudget::Time(Duration::from_millis(20)));
+        let purchase = strategy.buy_a_card(&view, &mut GameRng::seed_from_u64(0));
+        assert!(get_legal_purchases(&players[0], &card_deck, &config).contains(&purchase));
+    }
+
+    #[test]
+    fn test_mcts_game() {
+        let config = GameConfig::default();
+        let strategies: Vec<Box<dyn PlayerStrategy>> = vec![
+            Box::new(MctsAI::new(SearchBudget::Iterations(20))),
+            Box::new(RandomAI),
+        ];
+        let mut game =
+            Game::new(get_players(2, &config), strategies, 4).expect("Config to be valid.");
+        game.run_to_completion().expect("Game to finish.");
+        assert!(game.winner.is_some());
+    }
+
+    // Each playout counts for every purchase it made, so no purchase has more playouts than the
+    // one it was made after.
+    fn assert_playouts_add_up(node: &Node) {
+        let child_playouts: u32 = node.children.iter().map(|child| child.playouts).sum();
+        assert!(child_playouts <= node.playouts);
+        assert!(node.wins <= node.playouts);
+        node.children.iter().for_each(assert_playouts_add_up);
+    }
+}
@@ -1,2 +1,128 @@
-pub mod human;
+pub mod greedy;
+pub mod mcts;
 pub mod random;
+
+#[cfg(feature = "millionaires-row")]
+use super::landmarks::LandmarkKind;
+use super::{
+    cards::CardKind,
+    config::GameConfig,
+    controller::{DiceRoll, GameRng, Purchase},
+    deck::Deck,
+    player::Player,
+};
+
+// Creates one of the built-in strategies from its id.
+pub fn create_strategy(id: &str) -> Option<Box<dyn PlayerStrategy>> {
+    match id {
+        "random" => Some(Box::new(random::RandomAI)),
+        "greedy" => Some(Box::new(greedy::GreedyAI)),
+        "mcts" => Some(Box::<mcts::MctsAI>::default()),
+        _ => None,
+    }
+}
+
+// A read-only view of the game state handed to a strategy when it needs to make a decision.
+pub struct GameView<'a> {
+    pub players: &'a [Player],
+    pub deck: &'a Deck,
+    pub config: &'a GameConfig,
+    pub current_turn: usize,
+    // The dice the current player has rolled this turn, or None before they roll.
+    pub roll: Option<DiceRoll>,
+}
+
+impl GameView<'_> {
+    pub fn get_player(&self, turn: usize) -> &Player {
+        self.players.get(turn).expect("Player to not be OOB.")
+    }
+
+    pub fn get_current_player(&self) -> &Player {
+        self.get_player(self.current_turn)
+    }
+}
+
+// Makes every decision for a single player. Implement this to add a new kind of player.
+// Any randomness must come from the provided rng so that seeded games can be reproduced.
+pub trait PlayerStrategy {
+    // A unique, stable identifier used to recreate the strategy, e.g. when loading a save file.
+    fn get_id(&self) -> &'static str;
+
+    fn get_kind_name(&self) -> &'static str;
+
+    // Whether decisions are made by a person at the terminal.
+    fn is_interactive(&self) -> bool {
+        false
+    }
+
+    fn get_roll_two_dice(&mut self, view: &GameView, rng: &mut GameRng) -> bool;
+
+    fn ask_reroll(&mut self, view: &GameView, roll: DiceRoll, rng: &mut GameRng) -> bool;
+
+    // Whether to add 2 to the dice total with the Harbor. Only asked when the total is 10 or more.
+    #[cfg(feature = "harbor")]
+    fn ask_add_two(&mut self, view: &GameView, roll_total: u8, rng: &mut GameRng) -> bool;
+
+    // Returns the player to trade with, their card and the owner's card, or None to not trade.
+    // Only asked when at least one trade is possible.
+    fn get_trade_establishments(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> Option<(usize, CardKind, CardKind)>;
+
+    fn get_player_to_steal_coins_from(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        amount: u8,
+        rng: &mut GameRng,
+    ) -> usize;
+
+    fn buy_a_card(&mut self, view: &GameView, rng: &mut GameRng) -> Purchase;
+
+    // Whether to invest a coin in the Tech Startup. Asked at the end of each of the owner's turns
+    // when they have a coin to invest.
+    #[cfg(feature = "millionaires-row")]
+    fn ask_invest(&mut self, view: &GameView, rng: &mut GameRng) -> bool;
+
+    // Returns the player to give an establishment to and the owner's card to give them. Only
+    // asked when the owner has a non-Major establishment.
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_give(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> (usize, CardKind);
+
+    // Only asked when the owner has a landmark that can be demolished.
+    #[cfg(feature = "millionaires-row")]
+    fn get_landmark_to_demolish(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> LandmarkKind;
+
+    // Returns the kind of establishment to close for renovation. Only asked when any player has
+    // an open non-Major establishment.
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_renovate(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> CardKind;
+
+    // Returns the establishment for the Exhibit Hall to activate instead, or None to keep it.
+    // Only asked when at least one establishment can be activated.
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_exhibit(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> Option<CardKind>;
+}
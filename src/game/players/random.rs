@@ -1,124 +1,157 @@
 use crate::game::{
-    cards::{get_card_icon, CardIcon, CardKind, CardStack},
-    controller::Deck,
-    landmarks::{get_landmark_cost, LandmarkKind, ALL_LANDMARKS},
-    player::Player,
+    cards::CardKind,
+    controller::{
+        get_legal_purchases, get_legal_steal_targets, get_legal_trades, DiceRoll, GameRng, Purchase,
+    },
+};
+#[cfg(feature = "millionaires-row")]
+use crate::game::{
+    controller::{
+        get_legal_demolitions, get_legal_exhibits, get_legal_gifts, get_legal_renovations,
+    },
+    landmarks::LandmarkKind,
 };
 use rand::Rng;
 
-pub fn get_roll_two_dice() -> bool {
-    let mut rng = rand::thread_rng();
-    rng.gen_bool(0.5)
-}
+use super::{GameView, PlayerStrategy};
 
-pub fn ask_reroll() -> bool {
-    let mut rng = rand::thread_rng();
-    rng.gen_bool(0.25)
-}
+// A computer player that makes mostly random decisions.
+pub struct RandomAI;
 
-pub fn get_trade_establishments(
-    players: &Vec<Player>,
-    owner_turn: usize,
-    player: &Player,
-) -> (usize, CardKind, CardKind) {
-    let mut rng = rand::thread_rng();
-
-    let available_players: Vec<&Player> = players
-        .iter()
-        .filter(|player| player.turn != owner_turn)
-        .collect();
-    let player_count = available_players.iter().count();
-    let other_player = available_players
-        .get(rng.gen_range(0..player_count))
-        .expect("Player to not be OOB.");
-
-    let available_other_cards: Vec<CardKind> = other_player
-        .cards
-        .iter()
-        .filter_map(|card| {
-            if get_card_icon(card.kind) != CardIcon::Major && card.count > 0 {
-                Some(card.kind)
-            } else {
-                None
-            }
-        })
-        .collect();
-    let other_player_card = available_other_cards
-        .get(rng.gen_range(0..available_other_cards.iter().count()))
-        .expect("Card to not be OOB.");
-
-    let available_cards: Vec<CardKind> = player
-        .cards
-        .iter()
-        .filter_map(|card| {
-            if get_card_icon(card.kind) != CardIcon::Major && card.count > 0 {
-                Some(card.kind)
-            } else {
-                None
-            }
-        })
-        .collect();
-    let player_card = available_cards
-        .get(rng.gen_range(0..available_cards.iter().count()))
-        .expect("Card to not be OOB.");
-
-    (
-        other_player.turn,
-        other_player_card.clone(),
-        player_card.clone(),
-    )
-}
+impl PlayerStrategy for RandomAI {
+    fn get_id(&self) -> &'static str {
+        "random"
+    }
 
-pub fn get_player_to_steal_coins_from(players: &Vec<Player>, owner_turn: usize) -> usize {
-    // Take from the player with the most coins.
-    players
-        .iter()
-        .filter(|player| player.turn != owner_turn)
-        .max_by_key(|player| player.coins)
-        .expect("Player to not be None.")
-        .turn
-}
+    fn get_kind_name(&self) -> &'static str {
+        "Computer"
+    }
+
+    fn get_roll_two_dice(&mut self, _view: &GameView, rng: &mut GameRng) -> bool {
+        rng.gen_bool(0.5)
+    }
 
-pub fn buy_a_card(
-    player: &Player,
-    card_deck: &Deck,
-) -> Option<(Option<CardKind>, Option<LandmarkKind>)> {
-    let mut rng = rand::thread_rng();
-
-    // Buy a landmark if possible.
-    let available_landmarks: Vec<LandmarkKind> = ALL_LANDMARKS
-        .into_iter()
-        .filter(|landmark| {
-            get_landmark_cost(landmark) <= player.coins && !player.landmarks.contains(landmark)
-        })
-        .collect();
-
-    let landmark_count = available_landmarks.iter().count();
-    if landmark_count > 0 {
-        let landmark = available_landmarks
-            .get(rng.gen_range(0..landmark_count))
-            .expect("Landmark to not be OOB.");
-        return Some((None, Some(landmark.clone())));
+    fn ask_reroll(&mut self, _view: &GameView, _roll: DiceRoll, rng: &mut GameRng) -> bool {
+        rng.gen_bool(0.25)
     }
 
-    // Sometimes don't buy.
-    if rng.gen_bool(0.15) {
-        return None;
+    #[cfg(feature = "harbor")]
+    fn ask_add_two(&mut self, _view: &GameView, _roll_total: u8, rng: &mut GameRng) -> bool {
+        rng.gen_bool(0.5)
     }
 
-    // Try to buy a card.
-    let available_cards: Vec<&CardStack> = card_deck
-        .into_iter()
-        .filter(|card| card.count > 0 && card.get_cost() <= player.coins)
-        .collect();
-    let card_count = available_cards.iter().count();
-    if card_count > 0 {
-        let card = available_cards
-            .get(rng.gen_range(0..card_count))
-            .expect("Card to not be OOB.");
-        return Some((Some(card.kind), None));
+    fn get_trade_establishments(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> Option<(usize, CardKind, CardKind)> {
+        let trades = get_legal_trades(view.players, owner_turn);
+        if trades.is_empty() {
+            return None;
+        }
+        trades.get(rng.gen_range(0..trades.len())).copied()
     }
 
-    // No cards we can afford.
-    None
+    fn get_player_to_steal_coins_from(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _amount: u8,
+        _rng: &mut GameRng,
+    ) -> usize {
+        // Take from the player with the most coins.
+        get_legal_steal_targets(view.players, owner_turn)
+            .into_iter()
+            .max_by_key(|turn| view.get_player(*turn).coins)
+            .expect("Player to not be None.")
+    }
+
+    fn buy_a_card(&mut self, view: &GameView, rng: &mut GameRng) -> Purchase {
+        let purchases = get_legal_purchases(view.get_current_player(), view.deck, view.config);
+
+        // Buy a landmark if possible.
+        let landmarks: Vec<&Purchase> = purchases
+            .iter()
+            .filter(|purchase| matches!(purchase, Purchase::Landmark(_)))
+            .collect();
+        if !landmarks.is_empty() {
+            return landmarks
+                .get(rng.gen_range(0..landmarks.len()))
+                .map(|purchase| (*purchase).clone())
+                .expect("Landmark to not be OOB.");
+        }
+
+        // Sometimes don't buy.
+        if rng.gen_bool(0.15) {
+            return Purchase::Pass;
+        }
+
+        // Try to buy a card.
+        let cards: Vec<&Purchase> = purchases
+            .iter()
+            .filter(|purchase| matches!(purchase, Purchase::Card(_)))
+            .collect();
+        if !cards.is_empty() {
+            return cards
+                .get(rng.gen_range(0..cards.len()))
+                .map(|purchase| (*purchase).clone())
+                .expect("Card to not be OOB.");
+        }
+
+        // No cards we can afford.
+        Purchase::Pass
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn ask_invest(&mut self, _view: &GameView, rng: &mut GameRng) -> bool {
+        rng.gen_bool(0.5)
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_give(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> (usize, CardKind) {
+        let gifts = get_legal_gifts(view.players, owner_turn);
+        gifts[rng.gen_range(0..gifts.len())]
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_landmark_to_demolish(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> LandmarkKind {
+        let landmarks = get_legal_demolitions(view.get_player(owner_turn));
+        landmarks[rng.gen_range(0..landmarks.len())].clone()
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_renovate(
+        &mut self,
+        view: &GameView,
+        _owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> CardKind {
+        let card_kinds = get_legal_renovations(view.players);
+        card_kinds[rng.gen_range(0..card_kinds.len())]
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_exhibit(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        rng: &mut GameRng,
+    ) -> Option<CardKind> {
+        let card_kinds = get_legal_exhibits(view.get_player(owner_turn));
+        if card_kinds.is_empty() || rng.gen_bool(0.5) {
+            return None;
+        }
+        card_kinds.get(rng.gen_range(0..card_kinds.len())).copied()
+    }
 }
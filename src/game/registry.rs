@@ -0,0 +1,400 @@
+use serde::Deserialize;
+use std::{collections::BTreeMap, fmt, fs, io, sync::OnceLock};
+
+use super::{
+    cards::{CardIcon, CardKind, CardOrder},
+    config::Expansion,
+    landmarks::LandmarkKind,
+};
+
+// The card definitions the game ships with, along with those of every expansion compiled in.
+fn get_default_cards() -> String {
+    [
+        include_str!("cards.toml"),
+        #[cfg(feature = "harbor")]
+        include_str!("cards_harbor.toml"),
+        #[cfg(feature = "millionaires-row")]
+        include_str!("cards_millionaires_row.toml"),
+    ]
+    .concat()
+}
+
+static CARD_REGISTRY: OnceLock<CardRegistry> = OnceLock::new();
+
+// What happens for each copy of a card when it activates. Effects only used by an expansion
+// are compiled in with its feature.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", deny_unknown_fields)]
+pub enum CardEffect {
+    // Get coins from the bank.
+    Bank {
+        coins: u8,
+    },
+    // Get coins from the player who rolled the dice.
+    TakeFromRoller {
+        coins: u8,
+    },
+    // Get coins from the bank for each establishment with the icon that the owner has.
+    Combo {
+        icon: CardIcon,
+        coins: u8,
+    },
+    // Get coins from every other player.
+    TakeFromAll {
+        coins: u8,
+    },
+    // Get coins from one player of the owner's choice.
+    TakeFromOne {
+        coins: u8,
+    },
+    // Trade a non-Major establishment with another player.
+    Trade,
+    // Get coins from the bank equal to a roll of two dice, rolled once per turn.
+    #[cfg(feature = "harbor")]
+    BankDiceRoll,
+    // Get coins from every other player for each of their establishments with any of the icons.
+    #[cfg(feature = "harbor")]
+    TakeFromAllPerIcon {
+        icons: Vec<CardIcon>,
+        coins: u8,
+    },
+    // Get half of the coins, rounded down, from every other player with at least the threshold.
+    #[cfg(feature = "harbor")]
+    TakeHalfFromRich {
+        threshold: u8,
+    },
+    // Get coins from the bank for each copy of the card that the owner has.
+    #[cfg(feature = "millionaires-row")]
+    ComboCard {
+        card: CardKind,
+        coins: u8,
+    },
+    // Get coins from the bank for each establishment with the icon that any player has.
+    #[cfg(feature = "millionaires-row")]
+    ComboAll {
+        icon: CardIcon,
+        coins: u8,
+    },
+    // Get every coin the player who rolled the dice has.
+    #[cfg(feature = "millionaires-row")]
+    TakeAllFromRoller,
+    // Pay coins to the bank.
+    #[cfg(feature = "millionaires-row")]
+    PayBank {
+        coins: u8,
+    },
+    // Give a non-Major establishment to another player, then get coins from the bank.
+    #[cfg(feature = "millionaires-row")]
+    GiveAway {
+        coins: u8,
+    },
+    // Demolish one of the owner's landmarks, then get coins from the bank.
+    #[cfg(feature = "millionaires-row")]
+    Demolish {
+        coins: u8,
+    },
+    // Share every player's coins evenly, with the bank making up any remainder.
+    #[cfg(feature = "millionaires-row")]
+    ShareEvenly,
+    // Close every establishment of a non-Major kind for renovation, then get coins from every
+    // other player for each of their establishments that closed.
+    #[cfg(feature = "millionaires-row")]
+    Renovate {
+        coins: u8,
+    },
+    // Get the coins invested in the card from every other player.
+    #[cfg(feature = "millionaires-row")]
+    TakeInvestedFromAll,
+    // Activate one of the owner's non-Major establishments instead, then return the card to the
+    // supply.
+    #[cfg(feature = "millionaires-row")]
+    Exhibit,
+}
+
+// A requirement on the number of landmarks built, not counting the ones every player starts
+// with, for a card to activate.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", deny_unknown_fields)]
+pub enum CardCondition {
+    OwnerLandmarksBelow { landmarks: usize },
+    RollerLandmarksAtLeast { landmarks: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CardDefinition {
+    pub kind: CardKind,
+    // The expansion the card comes from, or None for the base game.
+    #[serde(default)]
+    pub expansion: Option<Expansion>,
+    pub title: String,
+    pub icon: CardIcon,
+    pub order: CardOrder,
+    // The dice totals that activate the card.
+    pub activation: Vec<u8>,
+    // The landmark the owner must have built for the card to activate, if any.
+    #[serde(default)]
+    pub requires: Option<LandmarkKind>,
+    #[serde(default)]
+    pub condition: Option<CardCondition>,
+    pub cost: u8,
+    // Coins the bank pays the buyer, printed on the card as a negative cost.
+    #[serde(default)]
+    pub loan: u8,
+    // How many copies can be bought, unless the game config says otherwise.
+    pub supply: u8,
+    pub description: String,
+    pub effect: CardEffect,
+    // Whether the owner's establishments close for renovation after activating.
+    #[cfg(feature = "millionaires-row")]
+    #[serde(default)]
+    pub renovates: bool,
+}
+
+impl CardDefinition {
+    // Describes the activation numbers as they are printed on the card, e.g. "2-3".
+    pub fn get_activation_description(&self) -> String {
+        let mut ranges: Vec<(u8, u8)> = Vec::new();
+        for &roll in self.activation.iter() {
+            match ranges.last_mut() {
+                Some((_, end)) if *end + 1 == roll => *end = roll,
+                _ => ranges.push((roll, roll)),
+            }
+        }
+        ranges
+            .iter()
+            .map(|(start, end)| {
+                if start == end {
+                    start.to_string()
+                } else {
+                    format!("{}-{}", start, end)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+// Every card definition, keyed by kind.
+#[derive(Debug)]
+pub struct CardRegistry {
+    definitions: BTreeMap<CardKind, CardDefinition>,
+}
+
+#[derive(Debug)]
+pub enum CardRegistryError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    DuplicateCard(CardKind),
+    InvalidActivation(CardKind),
+    // A card's effect names a kind that the file leaves out.
+    #[cfg(feature = "millionaires-row")]
+    UndefinedCard {
+        card: CardKind,
+        referenced: CardKind,
+    },
+}
+
+impl fmt::Display for CardRegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardRegistryError::Io(error) => write!(f, "{}", error),
+            CardRegistryError::Toml(error) => write!(f, "Invalid card file: {}", error),
+            CardRegistryError::DuplicateCard(kind) => {
+                write!(f, "{:?} is defined more than once.", kind)
+            }
+            CardRegistryError::InvalidActivation(kind) => write!(
+                f,
+                "{:?} must activate on at least one roll, in order, between 1 and 14.",
+                kind
+            ),
+            #[cfg(feature = "millionaires-row")]
+            CardRegistryError::UndefinedCard { card, referenced } => write!(
+                f,
+                "{:?} refers to {:?}, which is not defined.",
+                card, referenced
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CardRegistryError {}
+
+impl From<io::Error> for CardRegistryError {
+    fn from(error: io::Error) -> Self {
+        CardRegistryError::Io(error)
+    }
+}
+
+impl From<toml::de::Error> for CardRegistryError {
+    fn from(error: toml::de::Error) -> Self {
+        CardRegistryError::Toml(error)
+    }
+}
+
+impl CardRegistry {
+    pub fn from_toml(toml: &str) -> Result<Self, CardRegistryError> {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct CardFile {
+            cards: Vec<CardDefinition>,
+        }
+
+        let mut definitions = BTreeMap::new();
+        for definition in toml::from_str::<CardFile>(toml)?.cards {
+            let kind = definition.kind;
+            let activation = &definition.activation;
+            if activation.is_empty()
+                || activation.iter().any(|roll| !(1..=14).contains(roll))
+                || activation.windows(2).any(|rolls| rolls[0] >= rolls[1])
+            {
+                return Err(CardRegistryError::InvalidActivation(kind));
+            }
+            if definitions.insert(kind, definition).is_some() {
+                return Err(CardRegistryError::DuplicateCard(kind));
+            }
+        }
+        #[cfg(feature = "millionaires-row")]
+        for definition in definitions.values() {
+            if let CardEffect::ComboCard { card, .. } = definition.effect {
+                if !definitions.contains_key(&card) {
+                    return Err(CardRegistryError::UndefinedCard {
+                        card: definition.kind,
+                        referenced: card,
+                    });
+                }
+            }
+        }
+        Ok(CardRegistry { definitions })
+    }
+
+    pub fn contains(&self, card_kind: CardKind) -> bool {
+        self.definitions.contains_key(&card_kind)
+    }
+
+    // Every defined kind, in the order they are displayed. Kinds without a definition are not
+    // part of the game.
+    pub fn get_card_kinds(&self) -> impl Iterator<Item = CardKind> + '_ {
+        self.definitions.keys().copied()
+    }
+
+    // The definition of the kind, or None when the cards leave it out.
+    pub fn get(&self, card_kind: CardKind) -> Option<&CardDefinition> {
+        self.definitions.get(&card_kind)
+    }
+}
+
+pub fn load_card_registry(path: &str) -> Result<CardRegistry, CardRegistryError> {
+    CardRegistry::from_toml(&fs::read_to_string(path)?)
+}
+
+// Replaces the built-in card definitions for the rest of the program. Only works before the
+// first card is looked up, so call it at startup. Returns the registry back if it is too late.
+pub fn install_card_registry(registry: CardRegistry) -> Result<(), CardRegistry> {
+    CARD_REGISTRY.set(registry)
+}
+
+pub fn get_card_registry() -> &'static CardRegistry {
+    CARD_REGISTRY.get_or_init(|| {
+        CardRegistry::from_toml(&get_default_cards()).expect("The built-in cards to be valid.")
+    })
+}
+
+// The definition of a card in play. Configs, saves and the players a game starts with are
+// checked against the registry, so only the kinds it defines get this far.
+pub fn get_card_definition(card_kind: CardKind) -> &'static CardDefinition {
+    get_card_registry()
+        .get(card_kind)
+        .expect("Cards in play to be defined.")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::cards::{CardIcon, CardKind};
+
+    use super::{get_default_cards, CardEffect, CardRegistry, CardRegistryError};
+
+    #[test]
+    fn test_default_cards() {
+        let registry = CardRegistry::from_toml(&get_default_cards()).expect("Cards to be valid.");
+        let expansion_count = if cfg!(feature = "harbor") { 10 } else { 0 }
+            + if cfg!(feature = "millionaires-row") {
+                14
+            } else {
+                0
+            };
+        assert_eq!(registry.get_card_kinds().count(), 15 + expansion_count);
+        assert_eq!(registry.get_card_kinds().next(), Some(CardKind::WheatField));
+        let definition = registry
+            .get(CardKind::CheeseFactory)
+            .expect("Cheese Factory to be defined.");
+        assert_eq!(
+            definition.effect,
+            CardEffect::Combo {
+                icon: CardIcon::Cow,
+                coins: 3
+            }
+        );
+        assert_eq!(
+            registry
+                .get(CardKind::FamilyRestaurant)
+                .expect("Family Restaurant to be defined.")
+                .get_activation_description(),
+            "9-10"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_and_left_out_cards() {
+        let default_cards = get_default_cards();
+        let toml = default_cards.replace("kind = \"Mine\"", "kind = \"Forest\"");
+        assert!(matches!(
+            CardRegistry::from_toml(&toml),
+            Err(CardRegistryError::DuplicateCard(CardKind::Forest))
+        ));
+
+        let end = default_cards
+            .rfind("[[cards]]")
+            .expect("To find the last card.");
+        let full = CardRegistry::from_toml(&default_cards).expect("Cards to be valid.");
+        let last_kind = full.get_card_kinds().last().expect("A card to be defined.");
+        let registry = CardRegistry::from_toml(&default_cards[..end]).expect("Cards to be valid.");
+        assert!(!registry.contains(last_kind));
+        assert!(registry.get(last_kind).is_none());
+        assert_eq!(
+            registry.get_card_kinds().count() + 1,
+            full.get_card_kinds().count()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "millionaires-row")]
+    fn test_undefined_card() {
+        let default_cards = get_default_cards();
+        let start = default_cards
+            .find("[[cards]]\nkind = \"Vineyard\"")
+            .expect("To find the Vineyard.");
+        let end = start
+            + default_cards[start + 1..]
+                .find("[[cards]]")
+                .expect("To find the card after the Vineyard.")
+            + 1;
+        let toml = format!("{}{}", &default_cards[..start], &default_cards[end..]);
+        assert!(matches!(
+            CardRegistry::from_toml(&toml),
+            Err(CardRegistryError::UndefinedCard {
+                card: CardKind::Winery,
+                referenced: CardKind::Vineyard
+            })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_activation() {
+        let toml = get_default_cards().replace("activation = [2, 3]", "activation = [3, 15]");
+        assert!(matches!(
+            CardRegistry::from_toml(&toml),
+            Err(CardRegistryError::InvalidActivation(CardKind::Bakery))
+        ));
+    }
+}
@@ -0,0 +1,562 @@
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::VecDeque, fmt, fs, io, rc::Rc};
+
+#[cfg(feature = "millionaires-row")]
+use super::landmarks::LandmarkKind;
+use super::{
+    cards::CardKind,
+    config::{ConfigError, GameConfig},
+    controller::{DiceRoll, GameError, GameRng, Purchase},
+    engine::Game,
+    player::Player,
+    players::{GameView, PlayerStrategy},
+};
+
+// Bump this whenever the record format changes in a way older versions can't read.
+pub const GAME_RECORD_VERSION: u32 = 5;
+
+// A single choice made by a player, in the order the engine asked for it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Decision {
+    RollTwoDice(bool),
+    Reroll(bool),
+    #[cfg(feature = "harbor")]
+    AddTwo(bool),
+    Trade {
+        other_player: usize,
+        other_player_card: CardKind,
+        player_card: CardKind,
+    },
+    DeclineTrade,
+    StealFrom(usize),
+    Purchase(Purchase),
+    #[cfg(feature = "millionaires-row")]
+    Invest(bool),
+    #[cfg(feature = "millionaires-row")]
+    GiveAway {
+        other_player: usize,
+        card: CardKind,
+    },
+    #[cfg(feature = "millionaires-row")]
+    Demolish(LandmarkKind),
+    #[cfg(feature = "millionaires-row")]
+    Renovate(CardKind),
+    #[cfg(feature = "millionaires-row")]
+    Exhibit(Option<CardKind>),
+}
+
+// Everything needed to play a game again: how it started and every decision made since.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub version: u32,
+    pub seed: u64,
+    pub config: GameConfig,
+    pub players: Vec<Player>,
+    // The id of the strategy that controlled each player.
+    pub strategies: Vec<String>,
+    pub decisions: Vec<Decision>,
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    UnsupportedVersion(u32),
+    // The record has a strategy for a different number of players than it started with.
+    StrategyCount {
+        players: usize,
+        strategies: usize,
+    },
+    // The game asked for a decision the record doesn't have next, e.g. because the record was
+    // cut short or edited. Counts decisions from 0.
+    Diverged {
+        index: usize,
+        expected: &'static str,
+        found: Option<Decision>,
+    },
+    Config(ConfigError),
+    Game(GameError),
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReplayError::Io(error) => write!(f, "{}", error),
+            ReplayError::Json(error) => write!(f, "Invalid game record: {}", error),
+            ReplayError::UnsupportedVersion(version) => write!(
+                f,
+                "Game record version {} is not supported, expected version {}.",
+                version, GAME_RECORD_VERSION
+            ),
+            ReplayError::StrategyCount {
+                players,
+                strategies,
+            } => write!(
+                f,
+                "The game record has {} players but {} strategies.",
+                players, strategies
+            ),
+            ReplayError::Diverged {
+                index,
+                expected,
+                found: Some(decision),
+            } => write!(
+                f,
+                "Expected {} at decision {} of the game record, found {:?}.",
+                expected, index, decision
+            ),
+            ReplayError::Diverged {
+                index,
+                expected,
+                found: None,
+            } => write!(
+                f,
+                "Expected {} at decision {}, but the game record ends there.",
+                expected, index
+            ),
+            ReplayError::Config(error) => write!(f, "{}", error),
+            ReplayError::Game(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<io::Error> for ReplayError {
+    fn from(error: io::Error) -> Self {
+        ReplayError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for ReplayError {
+    fn from(error: serde_json::Error) -> Self {
+        ReplayError::Json(error)
+    }
+}
+
+impl From<ConfigError> for ReplayError {
+    fn from(error: ConfigError) -> Self {
+        ReplayError::Config(error)
+    }
+}
+
+impl From<GameError> for ReplayError {
+    fn from(error: GameError) -> Self {
+        ReplayError::Game(error)
+    }
+}
+
+// The decisions of a game record still to be replayed, shared between every player of the
+// replayed game.
+pub struct ReplayLog {
+    decisions: VecDeque<Decision>,
+    // How many decisions have been replayed so far.
+    replayed: usize,
+    // Set once the game asks for a decision that the record doesn't have next.
+    divergence: Option<ReplayError>,
+}
+
+impl ReplayLog {
+    pub fn is_empty(&self) -> bool {
+        self.decisions.is_empty()
+    }
+
+    // Takes the next decision when it is the kind the game expects. Otherwise records the
+    // divergence and returns the fallback, as does every decision after it, so that the rest of
+    // the turn plays out without touching the record.
+    fn next_decision<T>(
+        &mut self,
+        expected: &'static str,
+        fallback: T,
+        take: impl FnOnce(Decision) -> Result<T, Decision>,
+    ) -> T {
+        if self.divergence.is_some() {
+            return fallback;
+        }
+        let found = match self.decisions.pop_front() {
+            Some(decision) => match take(decision) {
+                Ok(value) => {
+                    self.replayed += 1;
+                    return value;
+                }
+                Err(decision) => Some(decision),
+            },
+            None => None,
+        };
+        self.divergence = Some(ReplayError::Diverged {
+            index: self.replayed,
+            expected,
+            found,
+        });
+        fallback
+    }
+}
+
+impl GameRecord {
+    pub fn new(
+        seed: u64,
+        config: &GameConfig,
+        players: &[Player],
+        strategies: &[Box<dyn PlayerStrategy>],
+    ) -> Self {
+        GameRecord {
+            version: GAME_RECORD_VERSION,
+            seed,
+            config: config.clone(),
+            players: players.to_vec(),
+            strategies: strategies
+                .iter()
+                .map(|strategy| strategy.get_id().to_string())
+                .collect(),
+            decisions: Vec::new(),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, ReplayError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ReplayError> {
+        #[derive(Deserialize)]
+        struct Version {
+            version: u32,
+        }
+        let version = serde_json::from_str::<Version>(json)?.version;
+        if version != GAME_RECORD_VERSION {
+            return Err(ReplayError::UnsupportedVersion(version));
+        }
+        Ok(serde_json::from_str(json)?)
+    }
+
+    // Creates a game from the recorded start where every player repeats their recorded
+    // decisions, named after the strategies create_strategy makes from their ids. Returns the
+    // game along with the decisions still to be replayed.
+    pub fn into_replay(
+        self,
+        create_strategy: impl Fn(&str) -> Option<Box<dyn PlayerStrategy>>,
+    ) -> Result<(Game, Rc<RefCell<ReplayLog>>), ReplayError> {
+        if self.strategies.len() != self.players.len() {
+            return Err(ReplayError::StrategyCount {
+                players: self.players.len(),
+                strategies: self.strategies.len(),
+            });
+        }
+        let log = Rc::new(RefCell::new(ReplayLog {
+            decisions: VecDeque::from(self.decisions),
+            replayed: 0,
+            divergence: None,
+        }));
+        let strategies = self
+            .strategies
+            .iter()
+            .map(|id| {
+                Box::new(ReplayStrategy {
+                    kind_name: create_strategy(id)
+                        .map_or("Unknown", |strategy| strategy.get_kind_name()),
+                    log: Rc::clone(&log),
+                }) as Box<dyn PlayerStrategy>
+            })
+            .collect();
+        Ok((
+            Game::with_config(self.players, strategies, self.seed, self.config)?,
+            log,
+        ))
+    }
+}
+
+pub fn save_game_record(game: &Game, path: &str) -> Result<(), ReplayError> {
+    fs::write(path, game.record.to_json()?)?;
+    Ok(())
+}
+
+pub fn load_game_record(path: &str) -> Result<GameRecord, ReplayError> {
+    GameRecord::from_json(&fs::read_to_string(path)?)
+}
+
+// Plays a recorded game through to the last recorded decision. Stops at the end of the turn
+// where the game first asks for a decision the record doesn't have.
+pub fn replay_game(game: &mut Game, log: &Rc<RefCell<ReplayLog>>) -> Result<(), ReplayError> {
+    // Every turn ends with a purchase decision, so an empty log means the recording stopped
+    // before this turn started.
+    while game.winner.is_none() && !log.borrow().is_empty() {
+        game.play_turn()?;
+        if let Some(error) = log.borrow_mut().divergence.take() {
+            return Err(error);
+        }
+    }
+    Ok(())
+}
+
+// Repeats the decisions from a game record. Once the record diverges from the game, every
+// decision falls back to a placeholder so the turn can finish, and replay_game then reports the
+// divergence instead of the game it leaves behind.
+pub struct ReplayStrategy {
+    kind_name: &'static str,
+    log: Rc<RefCell<ReplayLog>>,
+}
+
+impl ReplayStrategy {
+    fn next_decision<T>(
+        &mut self,
+        expected: &'static str,
+        fallback: T,
+        take: impl FnOnce(Decision) -> Result<T, Decision>,
+    ) -> T {
+        self.log
+            .borrow_mut()
+            .next_decision(expected, fallback, take)
+    }
+}
+
+impl PlayerStrategy for ReplayStrategy {
+    fn get_id(&self) -> &'static str {
+        "replay"
+    }
+
+    fn get_kind_name(&self) -> &'static str {
+        self.kind_name
+    }
+
+    fn get_roll_two_dice(&mut self, _view: &GameView, _rng: &mut GameRng) -> bool {
+        self.next_decision("a dice count decision", false, |decision| match decision {
+            Decision::RollTwoDice(roll_two_dice) => Ok(roll_two_dice),
+            decision => Err(decision),
+        })
+    }
+
+    fn ask_reroll(&mut self, _view: &GameView, _roll: DiceRoll, _rng: &mut GameRng) -> bool {
+        self.next_decision("a reroll decision", false, |decision| match decision {
+            Decision::Reroll(reroll) => Ok(reroll),
+            decision => Err(decision),
+        })
+    }
+
+    #[cfg(feature = "harbor")]
+    fn ask_add_two(&mut self, _view: &GameView, _roll_total: u8, _rng: &mut GameRng) -> bool {
+        self.next_decision("a Harbor decision", false, |decision| match decision {
+            Decision::AddTwo(add_two) => Ok(add_two),
+            decision => Err(decision),
+        })
+    }
+
+    fn get_trade_establishments(
+        &mut self,
+        _view: &GameView,
+        _owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> Option<(usize, CardKind, CardKind)> {
+        self.next_decision("a trade decision", None, |decision| match decision {
+            Decision::Trade {
+                other_player,
+                other_player_card,
+                player_card,
+            } => Ok(Some((other_player, other_player_card, player_card))),
+            Decision::DeclineTrade => Ok(None),
+            decision => Err(decision),
+        })
+    }
+
+    fn get_player_to_steal_coins_from(
+        &mut self,
+        _view: &GameView,
+        owner_turn: usize,
+        _amount: u8,
+        _rng: &mut GameRng,
+    ) -> usize {
+        self.next_decision("a steal decision", owner_turn, |decision| match decision {
+            Decision::StealFrom(player) => Ok(player),
+            decision => Err(decision),
+        })
+    }
+
+    fn buy_a_card(&mut self, _view: &GameView, _rng: &mut GameRng) -> Purchase {
+        self.next_decision(
+            "a purchase decision",
+            Purchase::Pass,
+            |decision| match decision {
+                Decision::Purchase(purchase) => Ok(purchase),
+                decision => Err(decision),
+            },
+        )
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn ask_invest(&mut self, _view: &GameView, _rng: &mut GameRng) -> bool {
+        self.next_decision("an investment decision", false, |decision| match decision {
+            Decision::Invest(invest) => Ok(invest),
+            decision => Err(decision),
+        })
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_give(
+        &mut self,
+        _view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> (usize, CardKind) {
+        self.next_decision(
+            "a give away decision",
+            (owner_turn, CardKind::WheatField),
+            |decision| match decision {
+                Decision::GiveAway { other_player, card } => Ok((other_player, card)),
+                decision => Err(decision),
+            },
+        )
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_landmark_to_demolish(
+        &mut self,
+        _view: &GameView,
+        _owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> LandmarkKind {
+        self.next_decision(
+            "a demolition decision",
+            LandmarkKind::TrainStation,
+            |decision| match decision {
+                Decision::Demolish(landmark) => Ok(landmark),
+                decision => Err(decision),
+            },
+        )
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_renovate(
+        &mut self,
+        _view: &GameView,
+        _owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> CardKind {
+        self.next_decision(
+            "a renovation decision",
+            CardKind::Stadium,
+            |decision| match decision {
+                Decision::Renovate(card) => Ok(card),
+                decision => Err(decision),
+            },
+        )
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_exhibit(
+        &mut self,
+        _view: &GameView,
+        _owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> Option<CardKind> {
+        self.next_decision("an exhibit decision", None, |decision| match decision {
+            Decision::Exhibit(card) => Ok(card),
+            decision => Err(decision),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{
+        config::GameConfig, engine::Game, landmarks::LandmarkKind, players::create_strategy,
+    };
+
+    use super::{replay_game, Decision, GameRecord, ReplayError};
+    use crate::game::test_helpers::{get_players, get_strategies};
+
+    #[test]
+    fn test_replay_reproduces_final_state() {
+        let mut game = Game::new(
+            get_players(3, &GameConfig::default()),
+            get_strategies(3),
+            99,
+        )
+        .expect("Config to be valid.");
+        game.run_to_completion().expect("Game to finish.");
+
+        let json = game.record.to_json().expect("Record to be serialized.");
+        let record = GameRecord::from_json(&json).expect("Record to be deserialized.");
+        let (mut replayed_game, decisions) = record
+            .into_replay(create_strategy)
+            .expect("Record to be valid.");
+        replay_game(&mut replayed_game, &decisions).expect("Replay to succeed.");
+
+        assert!(decisions.borrow().is_empty());
+        assert_eq!(replayed_game.winner, game.winner);
+        assert_eq!(replayed_game.current_turn, game.current_turn);
+        for (player, replayed_player) in game.players.iter().zip(replayed_game.players.iter()) {
+            assert_eq!(replayed_player.coins, player.coins);
+            assert_eq!(replayed_player.landmarks, player.landmarks);
+            assert_eq!(replayed_player.cards, player.cards);
+        }
+    }
+
+    #[test]
+    fn test_replay_stops_at_end_of_record() {
+        let mut game = Game::new(get_players(3, &GameConfig::default()), get_strategies(3), 5)
+            .expect("Config to be valid.");
+        for _ in 0..10 {
+            game.play_turn().expect("Turn to be legal.");
+        }
+
+        let (mut replayed_game, decisions) = game
+            .record
+            .clone()
+            .into_replay(create_strategy)
+            .expect("Record to be valid.");
+        replay_game(&mut replayed_game, &decisions).expect("Replay to succeed.");
+
+        assert_eq!(replayed_game.winner, None);
+        assert_eq!(replayed_game.current_turn, game.current_turn);
+        for (player, replayed_player) in game.players.iter().zip(replayed_game.players.iter()) {
+            assert_eq!(replayed_player.coins, player.coins);
+        }
+    }
+
+    #[test]
+    fn test_replay_reports_divergence() {
+        // With the Train Station, every turn starts with a dice decision.
+        let mut players = get_players(3, &GameConfig::default());
+        for player in players.iter_mut() {
+            player.landmarks.push(LandmarkKind::TrainStation);
+        }
+        let mut game = Game::new(players, get_strategies(3), 5).expect("Config to be valid.");
+        for _ in 0..4 {
+            game.play_turn().expect("Turn to be legal.");
+        }
+
+        // A record cut short in the middle of a turn.
+        let mut record = game.record.clone();
+        let last_purchase = record.decisions.len() - 1;
+        record.decisions.pop();
+        let (mut replayed_game, decisions) = record
+            .into_replay(create_strategy)
+            .expect("Record to be valid.");
+        assert!(matches!(
+            replay_game(&mut replayed_game, &decisions),
+            Err(ReplayError::Diverged { index, found: None, .. }) if index == last_purchase
+        ));
+
+        // A record with a decision of the wrong kind.
+        let mut record = game.record.clone();
+        record.decisions[last_purchase] = Decision::Reroll(true);
+        let (mut replayed_game, decisions) = record
+            .into_replay(create_strategy)
+            .expect("Record to be valid.");
+        assert!(matches!(
+            replay_game(&mut replayed_game, &decisions),
+            Err(ReplayError::Diverged {
+                found: Some(Decision::Reroll(true)),
+                ..
+            })
+        ));
+
+        let mut record = game.record.clone();
+        record.strategies.pop();
+        assert!(matches!(
+            record.into_replay(create_strategy),
+            Err(ReplayError::StrategyCount {
+                players: 3,
+                strategies: 2
+            })
+        ));
+    }
+}
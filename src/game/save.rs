@@ -0,0 +1,317 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, fs, io};
+
+use super::{
+    cards::CardKind, config::ConfigError, controller::GameRng, deck::Deck, engine::Game,
+    marketplace::Marketplace, player::Player, players::PlayerStrategy, registry::get_card_registry,
+    replay::GameRecord,
+};
+
+// Bump this whenever the save file format changes in a way older versions can't read.
+pub const SAVE_FILE_VERSION: u32 = 7;
+
+// A snapshot of a game between turns.
+#[derive(Serialize, Deserialize)]
+pub struct SaveFile {
+    pub version: u32,
+    pub players: Vec<Player>,
+    // The id of the strategy controlling each player.
+    pub strategies: Vec<String>,
+    pub deck: Deck,
+    pub marketplace: Option<Marketplace>,
+    pub current_turn: usize,
+    pub winner: Option<usize>,
+    pub dice_rng: GameRng,
+    pub strategy_rng: GameRng,
+    // Kept so that a resumed game can still be replayed from the start.
+    pub record: GameRecord,
+}
+
+#[derive(Debug)]
+pub enum SaveError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    UnsupportedVersion(u32),
+    UnknownStrategy(String),
+    Config(ConfigError),
+    PlayerCount {
+        count: usize,
+        max_player_count: usize,
+    },
+    // The save has a strategy for a different number of players than it has players.
+    StrategyCount {
+        players: usize,
+        strategies: usize,
+    },
+    // The player whose turn it is, or the winner, isn't one of the players.
+    TurnNotFound(usize),
+    WinnerNotFound(usize),
+    // A card the loaded card registry leaves out, e.g. when saved with a different --cards file.
+    UndefinedCard(CardKind),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SaveError::Io(error) => write!(f, "{}", error),
+            SaveError::Json(error) => write!(f, "Invalid save file: {}", error),
+            SaveError::UnsupportedVersion(version) => write!(
+                f,
+                "Save file version {} is not supported, expected version {}.",
+                version, SAVE_FILE_VERSION
+            ),
+            SaveError::UnknownStrategy(id) => write!(f, "Unknown player strategy \"{}\".", id),
+            SaveError::Config(error) => write!(f, "Invalid saved config: {}", error),
+            SaveError::PlayerCount {
+                count,
+                max_player_count,
+            } => write!(
+                f,
+                "A game needs between 2 and {} players, not {}.",
+                max_player_count, count
+            ),
+            SaveError::StrategyCount {
+                players,
+                strategies,
+            } => write!(
+                f,
+                "The save has {} players but {} strategies.",
+                players, strategies
+            ),
+            SaveError::TurnNotFound(turn) => {
+                write!(
+                    f,
+                    "It is the turn of player {}, who is not in the game.",
+                    turn + 1
+                )
+            }
+            SaveError::WinnerNotFound(turn) => {
+                write!(f, "The winner, player {}, is not in the game.", turn + 1)
+            }
+            SaveError::UndefinedCard(card_kind) => write!(
+                f,
+                "{:?} is not one of the establishments being played with.",
+                card_kind
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<io::Error> for SaveError {
+    fn from(error: io::Error) -> Self {
+        SaveError::Io(error)
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(error: serde_json::Error) -> Self {
+        SaveError::Json(error)
+    }
+}
+
+impl From<ConfigError> for SaveError {
+    fn from(error: ConfigError) -> Self {
+        SaveError::Config(error)
+    }
+}
+
+impl SaveFile {
+    pub fn to_json(&self) -> Result<String, SaveError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, SaveError> {
+        // Check the version first so that older or newer files get a helpful error.
+        #[derive(Deserialize)]
+        struct Version {
+            version: u32,
+        }
+        let version = serde_json::from_str::<Version>(json)?.version;
+        if version != SAVE_FILE_VERSION {
+            return Err(SaveError::UnsupportedVersion(version));
+        }
+        Ok(serde_json::from_str(json)?)
+    }
+
+    // Recreates the game, creating each player's strategy from its id, e.g. with
+    // players::create_strategy for the built-in ones.
+    pub fn into_game(
+        self,
+        create_strategy: impl Fn(&str) -> Option<Box<dyn PlayerStrategy>>,
+    ) -> Result<Game, SaveError> {
+        self.validate()?;
+        let strategies = self
+            .strategies
+            .iter()
+            .map(|id| create_strategy(id).ok_or_else(|| SaveError::UnknownStrategy(id.clone())))
+            .collect::<Result<Vec<_>, _>>()?;
+        Game::from_save_file(self, strategies)
+    }
+
+    // Checks that a hand-edited or corrupted save describes a game that can be carried on.
+    pub fn validate(&self) -> Result<(), SaveError> {
+        let config = &self.record.config;
+        config.validate()?;
+        let count = self.players.len();
+        if count < 2 || count > config.max_player_count {
+            return Err(SaveError::PlayerCount {
+                count,
+                max_player_count: config.max_player_count,
+            });
+        }
+        if self.strategies.len() != count {
+            return Err(SaveError::StrategyCount {
+                players: count,
+                strategies: self.strategies.len(),
+            });
+        }
+        if self.current_turn >= count {
+            return Err(SaveError::TurnNotFound(self.current_turn));
+        }
+        if let Some(winner) = self.winner.filter(|winner| *winner >= count) {
+            return Err(SaveError::WinnerNotFound(winner));
+        }
+        let marketplace_cards = self
+            .marketplace
+            .iter()
+            .flat_map(|marketplace| marketplace.get_draw_pile().iter().copied());
+        match self
+            .players
+            .iter()
+            .chain(&self.record.players)
+            .flat_map(|player| player.cards.iter())
+            .chain(self.deck.iter())
+            .map(|card| card.kind)
+            .chain(marketplace_cards)
+            .find(|card_kind| !get_card_registry().contains(*card_kind))
+        {
+            Some(card_kind) => Err(SaveError::UndefinedCard(card_kind)),
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn save_game(game: &Game, path: &str) -> Result<(), SaveError> {
+    fs::write(path, game.to_save_file().to_json()?)?;
+    Ok(())
+}
+
+pub fn load_game(
+    path: &str,
+    create_strategy: impl Fn(&str) -> Option<Box<dyn PlayerStrategy>>,
+) -> Result<Game, SaveError> {
+    SaveFile::from_json(&fs::read_to_string(path)?)?.into_game(create_strategy)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{
+        config::GameConfig,
+        engine::Game,
+        players::{create_strategy, random::RandomAI},
+        test_helpers::{get_players, get_strategies},
+    };
+
+    use super::{SaveError, SaveFile, SAVE_FILE_VERSION};
+
+    #[test]
+    fn test_resumed_game_matches_original() {
+        let mut game = get_game();
+        for _ in 0..20 {
+            game.play_turn().expect("Turn to be legal.");
+        }
+
+        let json = game
+            .to_save_file()
+            .to_json()
+            .expect("Game to be serialized.");
+        let mut resumed_game = SaveFile::from_json(&json)
+            .and_then(|save_file| save_file.into_game(create_strategy))
+            .expect("Game to be restored.");
+        assert_eq!(resumed_game.current_turn, game.current_turn);
+
+        game.run_to_completion().expect("Game to finish.");
+        resumed_game.run_to_completion().expect("Game to finish.");
+        assert_eq!(resumed_game.winner, game.winner);
+        for (player, resumed_player) in game.players.iter().zip(resumed_game.players.iter()) {
+            assert_eq!(resumed_player.name, player.name);
+            assert_eq!(resumed_player.coins, player.coins);
+            assert_eq!(resumed_player.landmarks, player.landmarks);
+        }
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let json = get_game()
+            .to_save_file()
+            .to_json()
+            .expect("Game to be serialized.")
+            .replacen(
+                &format!("\"version\": {}", SAVE_FILE_VERSION),
+                "\"version\": 999",
+                1,
+            );
+        assert!(matches!(
+            SaveFile::from_json(&json),
+            Err(SaveError::UnsupportedVersion(999))
+        ));
+    }
+
+    #[test]
+    fn test_corrupted_save() {
+        let load = |corrupt: fn(&mut SaveFile)| {
+            let mut save_file = get_game().to_save_file();
+            corrupt(&mut save_file);
+            let json = save_file.to_json().expect("Game to be serialized.");
+            SaveFile::from_json(&json).and_then(|save_file| save_file.into_game(create_strategy))
+        };
+        assert!(matches!(
+            load(|save_file| save_file.current_turn = 3),
+            Err(SaveError::TurnNotFound(3))
+        ));
+        assert!(matches!(
+            load(|save_file| save_file.winner = Some(5)),
+            Err(SaveError::WinnerNotFound(5))
+        ));
+        assert!(matches!(
+            load(|save_file| {
+                save_file.strategies.pop();
+            }),
+            Err(SaveError::StrategyCount {
+                players: 3,
+                strategies: 2
+            })
+        ));
+        assert!(matches!(
+            load(|save_file| save_file.players.clear()),
+            Err(SaveError::PlayerCount { count: 0, .. })
+        ));
+        assert!(matches!(
+            load(|save_file| save_file.record.config.max_player_count = 2),
+            Err(SaveError::PlayerCount {
+                count: 3,
+                max_player_count: 2
+            })
+        ));
+        assert!(matches!(
+            load(|save_file| save_file.record.config.marketplace_piles = 0),
+            Err(SaveError::Config(_))
+        ));
+
+        let save_file = get_game().to_save_file();
+        assert!(matches!(
+            Game::from_save_file(save_file, vec![Box::new(RandomAI)]),
+            Err(SaveError::StrategyCount {
+                players: 3,
+                strategies: 1
+            })
+        ));
+    }
+
+    fn get_game() -> Game {
+        Game::new(get_players(3, &GameConfig::default()), get_strategies(3), 7)
+            .expect("Config to be valid.")
+    }
+}
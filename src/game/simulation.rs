@@ -0,0 +1,336 @@
+use std::{cell::RefCell, collections::BTreeMap, fmt, rc::Rc};
+
+use super::{
+    config::{ConfigError, GameConfig},
+    controller::GameError,
+    engine::{Game, TurnOutcome},
+    events::GameEvent,
+    landmarks::LandmarkKind,
+    player::Player,
+    players::{create_strategy, PlayerStrategy},
+};
+
+// Games still going after this many turns are abandoned, e.g. when no strategy ever buys.
+pub const MAX_GAME_TURNS: usize = 10_000;
+
+#[derive(Debug)]
+pub enum SimulationError {
+    UnknownStrategy(String),
+    PlayerCount(usize),
+    TooManyPlayers {
+        count: usize,
+        max_player_count: usize,
+    },
+    // A tournament table can't seat more strategies than are entered.
+    TableLargerThanField {
+        table_size: usize,
+        strategies: usize,
+    },
+    TurnLimit(u64),
+    Config(ConfigError),
+    Game(GameError),
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SimulationError::UnknownStrategy(id) => write!(f, "Unknown strategy \"{}\".", id),
+            SimulationError::PlayerCount(count) => {
+                write!(f, "A game needs 2 or more players, not {}.", count)
+            }
+            SimulationError::TooManyPlayers {
+                count,
+                max_player_count,
+            } => write!(
+                f,
+                "A game can have at most {} players, not {}.",
+                max_player_count, count
+            ),
+            SimulationError::TableLargerThanField {
+                table_size,
+                strategies,
+            } => write!(
+                f,
+                "A table of {} cannot be seated from {} strategies.",
+                table_size, strategies
+            ),
+            SimulationError::TurnLimit(seed) => write!(
+                f,
+                "The game with seed {} did not finish within {} turns.",
+                seed, MAX_GAME_TURNS
+            ),
+            SimulationError::Config(error) => write!(f, "{}", error),
+            SimulationError::Game(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for SimulationError {}
+
+impl From<ConfigError> for SimulationError {
+    fn from(error: ConfigError) -> Self {
+        SimulationError::Config(error)
+    }
+}
+
+impl From<GameError> for SimulationError {
+    fn from(error: GameError) -> Self {
+        SimulationError::Game(error)
+    }
+}
+
+// How a single simulated game went.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameSummary {
+    pub winner: usize,
+    pub turns: usize,
+    // The coins each player ended the game with.
+    pub coins: Vec<u8>,
+    // The turn each player first built each landmark on, counting from 1.
+    pub landmark_turns: Vec<BTreeMap<LandmarkKind, usize>>,
+}
+
+// The strategies for each seat, from their ids, e.g. "random,greedy,random".
+pub fn create_lineup(
+    ids: &[String],
+    config: &GameConfig,
+) -> Result<Vec<Box<dyn PlayerStrategy>>, SimulationError> {
+    if ids.len() < 2 {
+        return Err(SimulationError::PlayerCount(ids.len()));
+    }
+    if ids.len() > config.max_player_count {
+        return Err(SimulationError::TooManyPlayers {
+            count: ids.len(),
+            max_player_count: config.max_player_count,
+        });
+    }
+    ids.iter().map(|id| create_simulated_strategy(id)).collect()
+}
+
+// The built-in strategy with the given id, all of which play without a person.
+pub fn create_simulated_strategy(id: &str) -> Result<Box<dyn PlayerStrategy>, SimulationError> {
+    create_strategy(id).ok_or_else(|| SimulationError::UnknownStrategy(id.to_string()))
+}
+
+// Plays a game between the strategies from start to finish.
+pub fn simulate_game(
+    lineup: &[String],
+    config: &GameConfig,
+    seed: u64,
+) -> Result<GameSummary, SimulationError> {
+    let strategies = create_lineup(lineup, config)?;
+    let players = strategies
+        .iter()
+        .enumerate()
+        .map(|(turn, strategy)| {
+            Player::new(
+                turn,
+                format!("{} {}", strategy.get_kind_name(), turn + 1),
+                config,
+            )
+        })
+        .collect();
+    let mut game = Game::with_config(players, strategies, seed, config.clone())?;
+
+    let built = Rc::new(RefCell::new(Vec::new()));
+    let subscriber_built = Rc::clone(&built);
+    game.subscribe(Box::new(move |event: &GameEvent, _: &[Player]| {
+        if let GameEvent::LandmarkBuilt {
+            player, landmark, ..
+        } = event
+        {
+            subscriber_built
+                .borrow_mut()
+                .push((*player, landmark.clone()));
+        }
+    }));
+
+    let mut landmark_turns = vec![BTreeMap::new(); lineup.len()];
+    for turn in 1..=MAX_GAME_TURNS {
+        let outcome = game.play_turn()?;
+        for (player, landmark) in built.borrow_mut().drain(..) {
+            landmark_turns[player].entry(landmark).or_insert(turn);
+        }
+        if outcome == TurnOutcome::Won {
+            return Ok(GameSummary {
+                winner: game.winner.expect("Game to have a winner."),
+                turns: turn,
+                coins: game.players.iter().map(|player| player.coins).collect(),
+                landmark_turns,
+            });
+        }
+    }
+    Err(SimulationError::TurnLimit(seed))
+}
+
+// The results of one seat across every simulated game.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SeatReport {
+    pub strategy: String,
+    pub wins: usize,
+    pub total_coins: u64,
+    // How many games each landmark was built in, and the sum of the turns it was built on.
+    pub landmarks_built: BTreeMap<LandmarkKind, (usize, usize)>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SimulationReport {
+    pub games: usize,
+    pub total_turns: usize,
+    pub seats: Vec<SeatReport>,
+}
+
+impl SimulationReport {
+    pub fn new(lineup: &[String]) -> Self {
+        SimulationReport {
+            games: 0,
+            total_turns: 0,
+            seats: lineup
+                .iter()
+                .map(|id| SeatReport {
+                    strategy: id.clone(),
+                    ..SeatReport::default()
+                })
+                .collect(),
+        }
+    }
+
+    pub fn add_game(&mut self, summary: &GameSummary) {
+        self.games += 1;
+        self.total_turns += summary.turns;
+        for (turn, seat) in self.seats.iter_mut().enumerate() {
+            if summary.winner == turn {
+                seat.wins += 1;
+            }
+            seat.total_coins += u64::from(summary.coins[turn]);
+            for (landmark, built_turn) in &summary.landmark_turns[turn] {
+                let (count, total_turns) =
+                    seat.landmarks_built.entry(landmark.clone()).or_default();
+                *count += 1;
+                *total_turns += built_turn;
+            }
+        }
+    }
+
+    pub fn get_average_turns(&self) -> f64 {
+        get_average(self.total_turns as f64, self.games)
+    }
+
+    pub fn get_win_rate(&self, turn: usize) -> f64 {
+        get_average(self.seats[turn].wins as f64, self.games)
+    }
+
+    pub fn get_average_coins(&self, turn: usize) -> f64 {
+        get_average(self.seats[turn].total_coins as f64, self.games)
+    }
+
+    // The share of games the seat built the landmark in, and the average turn it was built on.
+    pub fn get_landmark_timing(&self, turn: usize, landmark: &LandmarkKind) -> Option<(f64, f64)> {
+        let (count, total_turns) = self.seats[turn].landmarks_built.get(landmark)?;
+        Some((
+            get_average(*count as f64, self.games),
+            get_average(*total_turns as f64, *count),
+        ))
+    }
+}
+
+fn get_average(total: f64, count: usize) -> f64 {
+    if count == 0 {
+        0.0
+    } else {
+        total / count as f64
+    }
+}
+
+// Plays the games one after another, the first with the seed and each next one with the seed
+// after, so any single game can be played again on its own.
+pub fn run_simulation(
+    lineup: &[String],
+    config: &GameConfig,
+    game_count: usize,
+    seed: u64,
+) -> Result<SimulationReport, SimulationError> {
+    let mut report = SimulationReport::new(lineup);
+    for game_seed in (0..game_count as u64).map(|index| seed.wrapping_add(index)) {
+        report.add_game(&simulate_game(lineup, config, game_seed)?);
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{config::GameConfig, landmarks::LandmarkKind};
+
+    use super::{create_lineup, run_simulation, simulate_game, SimulationError};
+
+    fn get_lineup(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_create_lineup() {
+        let mut config = GameConfig::default();
+        assert_eq!(
+            create_lineup(&get_lineup(&["random", "greedy"]), &config)
+                .expect("Lineup to be created.")
+                .len(),
+            2
+        );
+        assert!(matches!(
+            create_lineup(&get_lineup(&["random", "nobody"]), &config),
+            Err(SimulationError::UnknownStrategy(id)) if id == "nobody"
+        ));
+        assert!(matches!(
+            create_lineup(&get_lineup(&["random", "human"]), &config),
+            Err(SimulationError::UnknownStrategy(id)) if id == "human"
+        ));
+        assert!(matches!(
+            create_lineup(&get_lineup(&["random"]), &config),
+            Err(SimulationError::PlayerCount(1))
+        ));
+        config.max_player_count = 2;
+        assert!(matches!(
+            create_lineup(&get_lineup(&["random", "greedy", "random"]), &config),
+            Err(SimulationError::TooManyPlayers {
+                count: 3,
+                max_player_count: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_simulate_game() {
+        let config = GameConfig::default();
+        let lineup = get_lineup(&["random", "greedy", "random"]);
+        let summary = simulate_game(&lineup, &config, 12).expect("Game to finish.");
+        assert_eq!(summary.coins.len(), 3);
+        assert_eq!(
+            summary.landmark_turns[summary.winner].len(),
+            config.get_landmarks().len()
+        );
+        assert!(summary.landmark_turns[summary.winner]
+            .values()
+            .all(|turn| *turn <= summary.turns));
+        assert_eq!(
+            simulate_game(&lineup, &config, 12).expect("Game to finish."),
+            summary
+        );
+    }
+
+    #[test]
+    fn test_run_simulation() {
+        let config = GameConfig::default();
+        let lineup = get_lineup(&["random", "greedy"]);
+        let report = run_simulation(&lineup, &config, 5, 40).expect("Games to finish.");
+        assert_eq!(report.games, 5);
+        assert_eq!(report.seats.iter().map(|seat| seat.wins).sum::<usize>(), 5);
+        assert!(report.get_average_turns() > 0.0);
+        let rate = report.get_win_rate(0) + report.get_win_rate(1);
+        assert!((rate - 1.0).abs() < f64::EPSILON);
+        let winner = if report.seats[0].wins > 0 { 0 } else { 1 };
+        let (share, turn) = report
+            .get_landmark_timing(winner, &LandmarkKind::RadioTower)
+            .expect("Winner to have built the Radio Tower.");
+        assert!(share > 0.0 && turn > 0.0);
+    }
+}
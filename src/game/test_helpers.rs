@@ -0,0 +1,17 @@
+use super::{
+    config::GameConfig,
+    player::Player,
+    players::{random::RandomAI, PlayerStrategy},
+};
+
+pub fn get_players(player_count: usize, config: &GameConfig) -> Vec<Player> {
+    (0..player_count)
+        .map(|turn| Player::new(turn, format!("Player {}", turn + 1), config))
+        .collect()
+}
+
+pub fn get_strategies(player_count: usize) -> Vec<Box<dyn PlayerStrategy>> {
+    (0..player_count)
+        .map(|_| Box::new(RandomAI) as Box<dyn PlayerStrategy>)
+        .collect()
+}
@@ -0,0 +1,301 @@
+use std::f64::consts::LN_10;
+
+use super::{
+    config::GameConfig,
+    simulation::{create_simulated_strategy, simulate_game, SimulationError},
+};
+
+pub const BASE_ELO: f64 = 1500.0;
+
+// Every pair of strategies starts as if they had drawn one game, so that ratings stay finite for
+// a strategy that won or lost every game.
+const PRIOR_GAMES: f64 = 1.0;
+
+// Rating fits stop once no rating moves by more than this many Elo points.
+const RATING_TOLERANCE: f64 = 1e-6;
+const MAX_RATING_ITERATIONS: usize = 10_000;
+
+// The z-score of a 95% confidence interval.
+const CONFIDENCE_Z: f64 = 1.96;
+
+// The head-to-head results between every pair of strategies in a tournament.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TournamentReport {
+    pub strategies: Vec<String>,
+    pub games: usize,
+    // How many times the strategy at the first index won a game the second one played in.
+    pub wins: Vec<Vec<usize>>,
+    pub games_played: Vec<usize>,
+    pub games_won: Vec<usize>,
+}
+
+// A strategy's place on the leaderboard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    pub strategy: String,
+    pub games: usize,
+    pub wins: usize,
+    pub elo: f64,
+    // Half the width of the 95% confidence interval around the rating.
+    pub elo_margin: f64,
+}
+
+impl TournamentReport {
+    pub fn new(strategies: &[String]) -> Self {
+        let count = strategies.len();
+        TournamentReport {
+            strategies: strategies.to_vec(),
+            games: 0,
+            wins: vec![vec![0; count]; count],
+            games_played: vec![0; count],
+            games_won: vec![0; count],
+        }
+    }
+
+    // Records a game between the strategies at the given indexes. The winner counts as having
+    // beaten each of the others, while the losers' games against each other decide nothing.
+    pub fn add_game(&mut self, seating: &[usize], winner: usize) {
+        self.games += 1;
+        self.games_won[winner] += 1;
+        for &strategy in seating {
+            self.games_played[strategy] += 1;
+            if strategy != winner {
+                self.wins[winner][strategy] += 1;
+            }
+        }
+    }
+
+    // The standings from the highest rated strategy to the lowest.
+    pub fn get_standings(&self) -> Vec<Standing> {
+        let ratings = get_ratings(&self.wins);
+        let mut standings: Vec<Standing> = self
+            .strategies
+            .iter()
+            .enumerate()
+            .map(|(index, strategy)| Standing {
+                strategy: strategy.clone(),
+                games: self.games_played[index],
+                wins: self.games_won[index],
+                elo: ratings[index].0,
+                elo_margin: ratings[index].1,
+            })
+            .collect();
+        standings.sort_by(|standing, other_standing| other_standing.elo.total_cmp(&standing.elo));
+        standings
+    }
+}
+
+// Every ordered way to seat table_size of the strategies, so that each strategy plays every
+// seat against every combination of opponents.
+pub fn get_seatings(strategy_count: usize, table_size: usize) -> Vec<Vec<usize>> {
+    if table_size == 0 {
+        return vec![Vec::new()];
+    }
+    let mut seatings = Vec::new();
+    for seating in get_seatings(strategy_count, table_size - 1) {
+        for strategy in (0..strategy_count).filter(|strategy| !seating.contains(strategy)) {
+            let mut seating = seating.clone();
+            seating.push(strategy);
+            seatings.push(seating);
+        }
+    }
+    seatings
+}
+
+// Plays every seating of the strategies the given number of times. The nth game of every seating
+// is played with the same seed, so every strategy gets the same dice from each seat.
+pub fn run_tournament(
+    strategies: &[String],
+    config: &GameConfig,
+    table_size: usize,
+    games_per_seating: usize,
+    seed: u64,
+) -> Result<TournamentReport, SimulationError> {
+    if table_size < 2 {
+        return Err(SimulationError::PlayerCount(table_size));
+    }
+    if table_size > config.max_player_count {
+        return Err(SimulationError::TooManyPlayers {
+            count: table_size,
+            max_player_count: config.max_player_count,
+        });
+    }
+    if table_size > strategies.len() {
+        return Err(SimulationError::TableLargerThanField {
+            table_size,
+            strategies: strategies.len(),
+        });
+    }
+    for strategy in strategies {
+        create_simulated_strategy(strategy)?;
+    }
+
+    let mut report = TournamentReport::new(strategies);
+    for game in 0..games_per_seating as u64 {
+        for seating in get_seatings(strategies.len(), table_size) {
+            let lineup: Vec<String> = seating
+                .iter()
+                .map(|strategy| strategies[*strategy].clone())
+                .collect();
+            let summary = simulate_game(&lineup, config, seed.wrapping_add(game))?;
+            report.add_game(&seating, seating[summary.winner]);
+        }
+    }
+    Ok(report)
+}
+
+// Fits Bradley-Terry strengths to the head-to-head results and returns each strategy's Elo
+// rating, centred on BASE_ELO, with the margin of its 95% confidence interval.
+fn get_ratings(wins: &[Vec<usize>]) -> Vec<(f64, f64)> {
+    let count = wins.len();
+    let get_games =
+        |index: usize, other: usize| (wins[index][other] + wins[other][index]) as f64 + PRIOR_GAMES;
+    let total_wins: Vec<f64> = (0..count)
+        .map(|index| {
+            (0..count)
+                .filter(|other| *other != index)
+                .map(|other| wins[index][other] as f64 + PRIOR_GAMES / 2.0)
+                .sum()
+        })
+        .collect();
+
+    // Minorization-maximization updates, which converge for any results once the prior is added.
+    let mut strengths = vec![1.0; count];
+    for _ in 0..MAX_RATING_ITERATIONS {
+        let mut next_strengths: Vec<f64> = (0..count)
+            .map(|index| {
+                let denominator: f64 = (0..count)
+                    .filter(|other| *other != index)
+                    .map(|other| get_games(index, other) / (strengths[index] + strengths[other]))
+                    .sum();
+                total_wins[index] / denominator
+            })
+            .collect();
+        // Only the ratios between strengths matter, so keep their product at 1.
+        let scale = get_mean_log(&next_strengths).exp();
+        for strength in next_strengths.iter_mut() {
+            *strength /= scale;
+        }
+        let change = strengths
+            .iter()
+            .zip(&next_strengths)
+            .map(|(strength, next_strength)| to_elo(next_strength / strength).abs())
+            .fold(0.0, f64::max);
+        strengths = next_strengths;
+        if change < RATING_TOLERANCE {
+            break;
+        }
+    }
+
+    (0..count)
+        .map(|index| {
+            // The standard error comes from the Fisher information of the strength.
+            let information: f64 = (0..count)
+                .filter(|other| *other != index)
+                .map(|other| {
+                    let chance = strengths[index] / (strengths[index] + strengths[other]);
+                    get_games(index, other) * chance * (1.0 - chance)
+                })
+                .sum();
+            (
+                BASE_ELO + to_elo(strengths[index]),
+                CONFIDENCE_Z * 400.0 / LN_10 / information.sqrt(),
+            )
+        })
+        .collect()
+}
+
+fn get_mean_log(values: &[f64]) -> f64 {
+    values.iter().map(|value| value.ln()).sum::<f64>() / values.len() as f64
+}
+
+// Converts a ratio of strengths to a difference in Elo points.
+fn to_elo(ratio: f64) -> f64 {
+    400.0 * ratio.log10()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::{config::GameConfig, simulation::SimulationError};
+
+    use super::{get_seatings, run_tournament, TournamentReport, BASE_ELO};
+
+    #[test]
+    fn test_get_seatings() {
+        let seatings = get_seatings(3, 2);
+        assert_eq!(seatings.len(), 6);
+        assert!(seatings.contains(&vec![0, 1]) && seatings.contains(&vec![1, 0]));
+        assert_eq!(get_seatings(4, 3).len(), 24);
+    }
+
+    #[test]
+    fn test_ratings() {
+        let mut report = TournamentReport::new(&get_strategies(&["a", "b", "c"]));
+        for _ in 0..30 {
+            report.add_game(&[0, 1], 0);
+            report.add_game(&[1, 2], 1);
+            report.add_game(&[0, 2], 0);
+        }
+        for _ in 0..10 {
+            report.add_game(&[0, 1], 1);
+        }
+
+        let standings = report.get_standings();
+        let names: Vec<&str> = standings
+            .iter()
+            .map(|standing| standing.strategy.as_str())
+            .collect();
+        assert_eq!(names, vec!["a", "b", "c"]);
+        assert_eq!(standings[0].games, 70);
+        assert_eq!(standings[0].wins, 60);
+        let mean = standings.iter().map(|standing| standing.elo).sum::<f64>() / 3.0;
+        assert!((mean - BASE_ELO).abs() < 1e-6);
+        assert!(standings
+            .iter()
+            .all(|standing| standing.elo_margin > 0.0 && standing.elo_margin < 400.0));
+    }
+
+    #[test]
+    fn test_multiplayer_games() {
+        let mut report = TournamentReport::new(&get_strategies(&["a", "b", "c"]));
+        report.add_game(&[0, 1, 2], 2);
+        report.add_game(&[2, 0, 1], 2);
+        let standings = report.get_standings();
+        assert_eq!(standings[0].strategy, "c");
+        assert_eq!(standings[0].games, 2);
+        assert_eq!(standings[0].wins, 2);
+    }
+
+    #[test]
+    fn test_run_tournament() {
+        let config = GameConfig::default();
+        let strategies = get_strategies(&["random", "greedy"]);
+        let report = run_tournament(&strategies, &config, 2, 3, 9).expect("Games to finish.");
+        assert_eq!(report.games, 6);
+        assert_eq!(report.wins[0][1] + report.wins[1][0], 6);
+
+        assert!(matches!(
+            run_tournament(&strategies, &config, 3, 1, 9),
+            Err(SimulationError::TableLargerThanField {
+                table_size: 3,
+                strategies: 2
+            })
+        ));
+        assert!(matches!(
+            run_tournament(&strategies, &config, 1, 1, 9),
+            Err(SimulationError::PlayerCount(1))
+        ));
+        let strategies = get_strategies(&["random", "greedy", "random", "greedy", "random"]);
+        assert!(matches!(
+            run_tournament(&strategies, &config, 5, 1, 9),
+            Err(SimulationError::TooManyPlayers {
+                count: 5,
+                max_player_count: 4
+            })
+        ));
+    }
+
+    fn get_strategies(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+}
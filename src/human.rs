@@ -0,0 +1,115 @@
+#[cfg(feature = "millionaires-row")]
+use dice_valley::game::landmarks::LandmarkKind;
+use dice_valley::game::{
+    cards::CardKind,
+    controller::{DiceRoll, GameRng, Purchase},
+    players::{self, GameView, PlayerStrategy},
+};
+
+use crate::ui;
+
+// Creates a human player or one of the library's built-in strategies from its id.
+pub fn create_strategy(id: &str) -> Option<Box<dyn PlayerStrategy>> {
+    match id {
+        "human" => Some(Box::new(Human)),
+        _ => players::create_strategy(id),
+    }
+}
+
+// A player who makes decisions through the terminal.
+pub struct Human;
+
+impl PlayerStrategy for Human {
+    fn get_id(&self) -> &'static str {
+        "human"
+    }
+
+    fn get_kind_name(&self) -> &'static str {
+        "Human"
+    }
+
+    fn is_interactive(&self) -> bool {
+        true
+    }
+
+    fn get_roll_two_dice(&mut self, view: &GameView, _rng: &mut GameRng) -> bool {
+        ui::get_roll_two_dice(view.players, view.current_turn)
+    }
+
+    fn ask_reroll(&mut self, _view: &GameView, _roll: DiceRoll, _rng: &mut GameRng) -> bool {
+        ui::ask_reroll()
+    }
+
+    #[cfg(feature = "harbor")]
+    fn ask_add_two(&mut self, _view: &GameView, roll_total: u8, _rng: &mut GameRng) -> bool {
+        ui::ask_add_two(roll_total)
+    }
+
+    fn get_trade_establishments(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> Option<(usize, CardKind, CardKind)> {
+        ui::get_player_to_trade_establishment_with(view.players, owner_turn)
+    }
+
+    fn get_player_to_steal_coins_from(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        amount: u8,
+        _rng: &mut GameRng,
+    ) -> usize {
+        ui::get_player_to_steal_coins_from(view.players, owner_turn, amount)
+    }
+
+    fn buy_a_card(&mut self, view: &GameView, _rng: &mut GameRng) -> Purchase {
+        ui::buy_a_card(view.players, view.current_turn, view.deck, view.config)
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn ask_invest(&mut self, view: &GameView, _rng: &mut GameRng) -> bool {
+        ui::ask_invest(view.get_current_player())
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_give(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> (usize, CardKind) {
+        ui::get_establishment_to_give(view.players, owner_turn)
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_landmark_to_demolish(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> LandmarkKind {
+        ui::get_landmark_to_demolish(view.get_player(owner_turn))
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_renovate(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> CardKind {
+        ui::get_establishment_to_renovate(view.players, owner_turn)
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_exhibit(
+        &mut self,
+        view: &GameView,
+        owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> Option<CardKind> {
+        ui::get_establishment_to_exhibit(view.get_player(owner_turn))
+    }
+}
@@ -0,0 +1,69 @@
+//! The Dice Valley game engine, a Machi Koro clone.
+//!
+//! Create a [`Game`] with a [`Player`] and [`PlayerStrategy`] for each seat, subscribe to its
+//! [`GameEvent`]s and call [`Game::play_turn`] or [`Game::run_to_completion`].
+
+pub mod game;
+
+pub use game::{
+    cards::{CardKind, CardStack},
+    config::GameConfig,
+    controller::{DiceRoll, GameError, GameRng, Purchase},
+    deck::Deck,
+    engine::{Game, TurnOutcome},
+    events::{GameEvent, GameEventSubscriber},
+    landmarks::LandmarkKind,
+    player::Player,
+    players::{GameView, PlayerStrategy},
+};
+
+// The decisions, effects and events of an expansion are left out of builds without its feature.
+// `cargo test --no-default-features` checks that none of them compile.
+#[cfg(all(doctest, not(feature = "harbor")))]
+mod without_harbor {
+    //! ```compile_fail
+    //! use dice_valley::game::controller::steal_half_coins_from_rich;
+    //! ```
+    //!
+    //! ```compile_fail
+    //! use dice_valley::{GameRng, GameView, PlayerStrategy};
+    //!
+    //! fn ask(strategy: &mut dyn PlayerStrategy, view: &GameView, rng: &mut GameRng) -> bool {
+    //!     strategy.ask_add_two(view, 10, rng)
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! let _ = dice_valley::GameEvent::HarborUsed { player: 0, total: 12 };
+    //! ```
+}
+
+#[cfg(all(doctest, not(feature = "millionaires-row")))]
+mod without_millionaires_row {
+    //! ```compile_fail
+    //! use dice_valley::game::controller::get_legal_renovations;
+    //! ```
+    //!
+    //! ```compile_fail
+    //! use dice_valley::{GameRng, GameView, PlayerStrategy};
+    //!
+    //! fn ask(strategy: &mut dyn PlayerStrategy, view: &GameView, rng: &mut GameRng) -> bool {
+    //!     strategy.ask_invest(view, rng)
+    //! }
+    //! ```
+    //!
+    //! ```compile_fail
+    //! let _ = dice_valley::GameError::CannotDemolish(dice_valley::LandmarkKind::TrainStation);
+    //! ```
+    //!
+    //! ```compile_fail
+    //! let _ = dice_valley::GameEvent::CoinInvested {
+    //!     player: 0,
+    //!     card: dice_valley::CardKind::WheatField,
+    //! };
+    //! ```
+    //!
+    //! ```compile_fail
+    //! let _ = dice_valley::CardStack::new(dice_valley::CardKind::WheatField, 1).closed;
+    //! ```
+}
@@ -1,159 +1,252 @@
-mod game;
-mod ui;
-
-use crate::game::{
-    cards::{CardIcon, CardKind},
-    controller::{
-        award_coins, award_coins_combo, buy_card_from_deck, buy_landmark, create_deck,
-        get_activatable_cards, has_player_won, roll_dice, steal_coins, steal_coins_from_all,
-        trade_establishments,
+use dice_valley::{
+    game::{
+        cli::parse_number,
+        config::{load_config, ConfigError, Expansion, GameConfig, SupplyMode},
+        controller::GameError,
+        registry::{install_card_registry, load_card_registry},
+        replay::{load_game_record, replay_game, save_game_record},
+        save::{load_game, save_game},
     },
-    landmarks::LandmarkKind,
+    Game,
 };
+use std::{env, process};
 
-pub const MAX_PLAYER_COUNT: usize = 4;
+mod human;
+mod ui;
 
-fn main() {
-    println!("Dice Valley");
+const USAGE: &str = "Usage: dice-valley [--seed <number>] [--load <path>] [--record <path>]
+                   [--config <path>] [--starting-coins <number>] [--max-players <number>]
+                   [--landmarks-to-win <number>] [--unlimited-majors] [--harbor]
+                   [--millionaires-row] [--marketplace] [--cards <path>]
+       dice-valley replay <path> [--cards <path>]";
 
-    let (mut players, player_count) = ui::get_players();
-    let mut card_deck = create_deck();
+// Options passed on the command line.
+struct Options {
+    seed: Option<u64>,
+    load_path: Option<String>,
+    record_path: Option<String>,
+    replay_path: Option<String>,
+    config_path: Option<String>,
+    // Settings that override the config file.
+    starting_coins: Option<u8>,
+    max_player_count: Option<usize>,
+    landmarks_to_win: Option<usize>,
+    unlimited_majors: bool,
+    harbor: bool,
+    millionaires_row: bool,
+    marketplace: bool,
+    cards_path: Option<String>,
+}
 
-    let mut current_turn: usize = 0;
-    loop {
-        let player = players.get(current_turn).expect("Player to not be OOB.");
-        ui::start_player_turn(&player);
+fn main() {
+    let options = parse_options(env::args().skip(1)).unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        eprintln!("{}", USAGE);
+        process::exit(1);
+    });
 
-        let should_roll_two_dice = if player.landmarks.contains(&LandmarkKind::TrainStation) {
-            player.get_roll_two_dice()
-        } else {
-            false
-        };
+    println!("Dice Valley");
 
-        let (mut first_die, mut second_die) = roll_dice(should_roll_two_dice);
-        let mut roll_total = first_die + second_die.unwrap_or_default();
-        ui::roll_result(first_die, second_die, roll_total);
+    if let Some(path) = &options.cards_path {
+        let registry = load_card_registry(path).unwrap_or_else(|error| {
+            eprintln!("Could not load {}: {}", path, error);
+            process::exit(1);
+        });
+        install_card_registry(registry).expect("Cards to be installed before they are used.");
+        println!("Playing with the cards from {}.", path);
+    }
+
+    if let Some(path) = options.replay_path {
+        replay(&path);
+        return;
+    }
 
-        if player.landmarks.contains(&LandmarkKind::RadioTower) && player.ask_reroll() {
-            (first_die, second_die) = roll_dice(should_roll_two_dice);
-            roll_total = first_die + second_die.unwrap_or_default();
-            ui::roll_result(first_die, second_die, roll_total);
+    let mut game = match options.load_path {
+        Some(path) => {
+            let game = load_game(&path, human::create_strategy).unwrap_or_else(|error| {
+                eprintln!("Could not load {}: {}", path, error);
+                process::exit(1);
+            });
+            println!("Loaded the game from {}.", path);
+            game
         }
+        None => {
+            let seed = options.seed.unwrap_or_else(rand::random);
+            println!("Seed: {}", seed);
 
-        let activatable_cards = get_activatable_cards(roll_total, current_turn, &players);
-        ui::show_activated_cards(&activatable_cards);
-
-        let before_coins = player.coins;
-        for card_stack in activatable_cards {
-            let shopping_mall_bonus = if (card_stack.card.get_icon() == CardIcon::Bread
-                || card_stack.card.get_icon() == CardIcon::Cup)
-                && players
-                    .get(card_stack.owner_turn)
-                    .expect("Player to not be OOB.")
-                    .landmarks
-                    .contains(&LandmarkKind::ShoppingMall)
-            {
-                1
-            } else {
-                0
-            };
-            for _ in 0..card_stack.card.count {
-                match card_stack.card.kind {
-                    CardKind::AppleOrchard => award_coins(&mut players, card_stack.owner_turn, 3),
-                    CardKind::Bakery => {
-                        award_coins(&mut players, card_stack.owner_turn, 1 + shopping_mall_bonus)
-                    }
-                    CardKind::BusinessCenter => {
-                        let player = players.get(current_turn).expect("Player to not be OOB.");
-                        let (other_player, other_player_card_kind, player_card_kind) =
-                            player.get_trade_establishments(&players, card_stack.owner_turn);
-                        trade_establishments(
-                            &mut players,
-                            card_stack.owner_turn,
-                            other_player,
-                            player_card_kind,
-                            other_player_card_kind,
-                        );
-                        0
-                    }
-                    CardKind::Cafe => steal_coins(
-                        &mut players,
-                        current_turn,
-                        card_stack.owner_turn,
-                        1 + shopping_mall_bonus,
-                    ),
-                    CardKind::CheeseFactory => {
-                        award_coins_combo(&mut players, card_stack.owner_turn, CardIcon::Cow, 3)
-                    }
-                    CardKind::ConvenienceStore => {
-                        award_coins(&mut players, card_stack.owner_turn, 3 + shopping_mall_bonus)
-                    }
-                    CardKind::FamilyRestaurant => steal_coins(
-                        &mut players,
-                        current_turn,
-                        card_stack.owner_turn,
-                        2 + shopping_mall_bonus,
-                    ),
-                    CardKind::Forest => award_coins(&mut players, card_stack.owner_turn, 1),
-                    CardKind::FruitAndVegetableMarket => {
-                        award_coins_combo(&mut players, card_stack.owner_turn, CardIcon::Wheat, 2)
-                    }
-                    CardKind::FurnitureFactory => {
-                        award_coins_combo(&mut players, card_stack.owner_turn, CardIcon::Gear, 3)
-                    }
-                    CardKind::Mine => award_coins(&mut players, card_stack.owner_turn, 5),
-                    CardKind::Ranch => award_coins(&mut players, card_stack.owner_turn, 1),
-                    CardKind::Stadium => {
-                        steal_coins_from_all(&mut players, card_stack.owner_turn, player_count, 2)
-                    }
-                    CardKind::TvStation => {
-                        let player = players.get(current_turn).expect("Player to not be OOB.");
-                        let from_player = player.get_player_to_steal_coins_from(
-                            &players,
-                            card_stack.owner_turn,
-                            5,
-                        );
-                        steal_coins(&mut players, from_player, card_stack.owner_turn, 5)
-                    }
-                    CardKind::WheatField => award_coins(&mut players, card_stack.owner_turn, 1),
-                };
-            }
+            let config = get_config(&options).unwrap_or_else(|error| {
+                eprintln!("{}", error);
+                process::exit(1);
+            });
+            let (players, strategies) = ui::get_players(&config);
+            Game::with_config(players, strategies, seed, config).unwrap_or_else(|error| {
+                eprintln!("{}", error);
+                process::exit(1);
+            })
         }
+    };
+    subscribe_printer(&mut game);
 
-        let player = players.get(current_turn).expect("Player to not be OOB.");
-        ui::share_post_distribution_results(player.coins, before_coins);
+    if !game
+        .strategies
+        .iter()
+        .any(|strategy| strategy.is_interactive())
+    {
+        let result = game.run_to_completion().map(|_| ());
+        write_record(&game, &options.record_path);
+        exit_on_error(result);
+        return;
+    }
 
-        let purchase_decision = player.buy_a_card(&card_deck);
-        ui::show_purchase_decision(&purchase_decision);
-        if purchase_decision.is_some() {
-            let (card_purchase, landmark_purchase) = purchase_decision.unwrap();
-            match card_purchase {
-                Some(card_kind) => {
-                    buy_card_from_deck(&mut players, current_turn, &mut card_deck, card_kind)
+    while game.get_winner().is_none() {
+        if game.get_current_strategy().is_interactive() {
+            while let Some(path) = ui::ask_save_path(game.get_current_player()) {
+                match save_game(&game, &path) {
+                    Ok(()) => println!("Saved the game to {}.", path),
+                    Err(error) => println!("Could not save to {}: {}", path, error),
                 }
-                None => buy_landmark(
-                    &mut players,
-                    current_turn,
-                    landmark_purchase.expect("Expected either a card or a landmark."),
-                ),
             }
         }
+        let result = game.play_turn().map(|_| ());
+        // Written after every turn so that the record survives the game being abandoned.
+        write_record(&game, &options.record_path);
+        exit_on_error(result);
+    }
+}
 
-        let player = players.get(current_turn).expect("Player to not be OOB.");
-        if has_player_won(player) {
-            ui::player_has_won(player);
-            break;
-        }
+fn exit_on_error(result: Result<(), GameError>) {
+    if let Err(error) = result {
+        eprintln!("The game cannot continue: {}", error);
+        process::exit(1);
+    }
+}
+
+fn replay(path: &str) {
+    let record = load_game_record(path).unwrap_or_else(|error| {
+        eprintln!("Could not load {}: {}", path, error);
+        process::exit(1);
+    });
+    println!(
+        "Replaying the game from {} with seed {}.",
+        path, record.seed
+    );
+
+    let game = record
+        .into_replay(human::create_strategy)
+        .and_then(|(mut game, decisions)| {
+            subscribe_printer(&mut game);
+            replay_game(&mut game, &decisions)?;
+            Ok(game)
+        })
+        .unwrap_or_else(|error| {
+            eprintln!("Could not replay {}: {}", path, error);
+            process::exit(1);
+        });
 
-        if player.landmarks.contains(&LandmarkKind::AmusementPark) && Some(first_die) == second_die
-        {
-            ui::amusement_park_turn();
-            continue;
+    if game.get_winner().is_none() {
+        println!();
+        println!("The recording ends here.");
+    }
+    ui::show_standings(&game.players);
+}
+
+fn subscribe_printer(game: &mut Game) {
+    let kind_names = game
+        .strategies
+        .iter()
+        .map(|strategy| strategy.get_kind_name())
+        .collect();
+    game.subscribe(Box::new(ui::EventPrinter::new(kind_names)));
+}
+
+fn write_record(game: &Game, record_path: &Option<String>) {
+    if let Some(path) = record_path {
+        if let Err(error) = save_game_record(game, path) {
+            eprintln!("Could not write the game record to {}: {}", path, error);
         }
+    }
+}
 
-        current_turn += 1;
-        if current_turn >= player_count {
-            current_turn = 0;
+// Loads the config file, if any, and applies the settings given on the command line on top.
+fn get_config(options: &Options) -> Result<GameConfig, ConfigError> {
+    let mut config = match &options.config_path {
+        Some(path) => load_config(path)?,
+        None => GameConfig::default(),
+    };
+    if let Some(starting_coins) = options.starting_coins {
+        config.starting_coins = starting_coins;
+    }
+    if let Some(max_player_count) = options.max_player_count {
+        config.max_player_count = max_player_count;
+    }
+    if let Some(landmarks_to_win) = options.landmarks_to_win {
+        config.landmarks_to_win = Some(landmarks_to_win);
+    }
+    if options.unlimited_majors {
+        config.limit_major_establishments = false;
+    }
+    if options.harbor {
+        config.expansions.insert(Expansion::Harbor);
+    }
+    if options.millionaires_row {
+        config.expansions.insert(Expansion::MillionairesRow);
+    }
+    if options.marketplace {
+        config.supply_mode = SupplyMode::Marketplace;
+    }
+    config.validate()?;
+    Ok(config)
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        seed: None,
+        load_path: None,
+        record_path: None,
+        replay_path: None,
+        config_path: None,
+        starting_coins: None,
+        max_player_count: None,
+        landmarks_to_win: None,
+        unlimited_majors: false,
+        harbor: false,
+        millionaires_row: false,
+        marketplace: false,
+        cards_path: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => options.seed = Some(parse_number(&arg, args.next())?),
+            "--load" => {
+                options.load_path = Some(args.next().ok_or("Expected a path after --load.")?);
+            }
+            "--record" => {
+                options.record_path = Some(args.next().ok_or("Expected a path after --record.")?);
+            }
+            "--config" => {
+                options.config_path = Some(args.next().ok_or("Expected a path after --config.")?);
+            }
+            "--starting-coins" => options.starting_coins = Some(parse_number(&arg, args.next())?),
+            "--max-players" => options.max_player_count = Some(parse_number(&arg, args.next())?),
+            "--landmarks-to-win" => {
+                options.landmarks_to_win = Some(parse_number(&arg, args.next())?)
+            }
+            "--unlimited-majors" => options.unlimited_majors = true,
+            "--harbor" => options.harbor = true,
+            "--millionaires-row" => options.millionaires_row = true,
+            "--marketplace" => options.marketplace = true,
+            "--cards" => {
+                options.cards_path = Some(args.next().ok_or("Expected a path after --cards.")?);
+            }
+            "replay" => {
+                options.replay_path = Some(args.next().ok_or("Expected a path after replay.")?);
+            }
+            _ => return Err(format!("Unknown argument \"{}\".", arg)),
         }
     }
+    // A saved game carries on with the dice it was saved with.
+    if options.seed.is_some() && options.load_path.is_some() {
+        return Err("--seed can't be used with --load.".to_string());
+    }
+    Ok(options)
 }
@@ -1,42 +1,55 @@
-use tabled::{
-    builder::Builder,
-    object::{Columns, Segment},
-    Alignment, Header, MaxWidth, Modify, Style,
+#[cfg(feature = "millionaires-row")]
+use dice_valley::game::controller::{
+    get_legal_demolitions, get_legal_exhibits, get_legal_gifts, get_legal_renovations,
 };
-
-use crate::{
-    game::{
-        cards::{get_card_title, CardIcon, CardKind, CardStack, CARD_KIND_COUNT},
-        controller::{create_player_deck, Deck, PlayerCardStack},
-        landmarks::{
-            get_landmark_cost, get_landmark_description, get_landmark_title, LandmarkKind,
-            ALL_LANDMARKS, LANDMARK_KIND_COUNT,
-        },
-        player::{Player, PlayerKind},
+use dice_valley::game::{
+    analysis::{get_income_forecast, get_income_gain, get_roll_choices},
+    cards::{get_card_title, CardKind, CardStack},
+    cli::print_table,
+    config::{GameConfig, SupplyMode},
+    controller::{
+        check_purchase, get_legal_purchases, get_legal_steal_targets, get_legal_trades, Purchase,
     },
-    MAX_PLAYER_COUNT,
+    deck::Deck,
+    events::{GameEvent, GameEventSubscriber},
+    landmarks::{get_landmark_description, get_landmark_title, LandmarkKind},
+    player::Player,
+    players::{greedy::GreedyAI, mcts::MctsAI, random::RandomAI, PlayerStrategy},
 };
 use std::io;
 
-pub fn get_players() -> (Vec<Player>, usize) {
-    let player_count = get_player_count();
+use crate::human::Human;
+
+pub fn get_players(config: &GameConfig) -> (Vec<Player>, Vec<Box<dyn PlayerStrategy>>) {
+    let player_count = get_player_count(config.max_player_count);
     let mut players = Vec::new();
+    let mut strategies = Vec::new();
 
     for turn in 0..player_count {
-        let name = get_player_name(turn + 1);
-        players.push(Player {
-            turn,
-            name,
-            kind: get_player_kind(),
-            cards: create_player_deck(),
-            coins: 0,
-            landmarks: Vec::new(),
-        });
+        players.push(Player::new(turn, get_player_name(turn + 1), config));
+        strategies.push(get_player_strategy());
     }
-    (players, player_count)
+    (players, strategies)
 }
 
-pub fn get_roll_two_dice() -> bool {
+pub fn get_roll_two_dice(players: &[Player], player_turn: usize) -> bool {
+    // Show what each number of dice is expected to bring, given the player's establishments.
+    let data = get_roll_choices(players, player_turn)
+        .iter()
+        .map(|choice| {
+            vec![
+                get_dice_title(choice.roll_two_dice).to_string(),
+                format!("{:+.2}", choice.expected_coins),
+                format!("{:.0}%", choice.activation_chance * 100.0),
+            ]
+        })
+        .collect();
+    print_table(
+        "Dice odds",
+        vec!["Dice", "Expected coins", "Activation chance"],
+        data,
+    );
+
     println!("How many dice will you roll: (1-2):");
     loop {
         return match get_input().trim().parse() {
@@ -56,9 +69,158 @@ pub fn get_roll_two_dice() -> bool {
     }
 }
 
-pub fn start_player_turn(player: &Player) {
+fn get_dice_title(roll_two_dice: bool) -> &'static str {
+    if roll_two_dice {
+        "2 dice"
+    } else {
+        "1 die"
+    }
+}
+
+// Prints every event of a game to the terminal.
+pub struct EventPrinter {
+    kind_names: Vec<&'static str>,
+}
+
+impl EventPrinter {
+    // Creates a printer that describes each player using the kind name at their turn.
+    pub fn new(kind_names: Vec<&'static str>) -> Self {
+        EventPrinter { kind_names }
+    }
+
+    fn get_kind_name(&self, turn: usize) -> &'static str {
+        self.kind_names.get(turn).expect("Kind name to not be OOB.")
+    }
+}
+
+impl GameEventSubscriber for EventPrinter {
+    fn on_event(&mut self, event: &GameEvent, players: &[Player]) {
+        match event {
+            GameEvent::TurnStarted { player } => {
+                start_player_turn(get_player(players, *player), self.get_kind_name(*player))
+            }
+            GameEvent::DiceRolled { roll, total, .. } | GameEvent::Rerolled { roll, total, .. } => {
+                roll_result(roll.0, roll.1, *total)
+            }
+            #[cfg(feature = "harbor")]
+            GameEvent::HarborUsed { total, .. } => {
+                println!("Added 2 with the Harbor for a total of {}.", total)
+            }
+            #[cfg(feature = "harbor")]
+            GameEvent::BonusDiceRolled {
+                card, roll, total, ..
+            } => {
+                println!("Rolling for the {}.", get_card_title(*card));
+                roll_result(roll.0, roll.1, *total)
+            }
+            GameEvent::LandmarkActivated { player, landmark } => println!(
+                "{}'s {} activated.",
+                get_player(players, *player).name,
+                get_landmark_title(landmark)
+            ),
+            GameEvent::CardActivated { owner, card, count } => println!(
+                "{}'s {} activated{}.",
+                get_player(players, *owner).name,
+                get_card_title(*card),
+                if *count > 1 {
+                    format!(" {} times", count)
+                } else {
+                    String::new()
+                }
+            ),
+            GameEvent::CoinsTransferred {
+                from, to, amount, ..
+            } => show_coins_transferred(players, *from, *to, *amount),
+            GameEvent::EstablishmentsTraded {
+                player,
+                other_player,
+                player_card,
+                other_player_card,
+            } => println!(
+                "{} traded their {} for {}'s {}.",
+                get_player(players, *player).name,
+                get_card_title(*player_card),
+                get_player(players, *other_player).name,
+                get_card_title(*other_player_card)
+            ),
+            #[cfg(feature = "millionaires-row")]
+            GameEvent::EstablishmentsClosed { player, card } => println!(
+                "{}'s {} closed for renovation.",
+                get_player(players, *player).name,
+                get_card_title(*card)
+            ),
+            #[cfg(feature = "millionaires-row")]
+            GameEvent::EstablishmentsReopened { player, card } => println!(
+                "{}'s {} reopened after renovation.",
+                get_player(players, *player).name,
+                get_card_title(*card)
+            ),
+            #[cfg(feature = "millionaires-row")]
+            GameEvent::CardHadNoEffect { player, card } => println!(
+                "{}'s {} had no effect.",
+                get_player(players, *player).name,
+                get_card_title(*card)
+            ),
+            #[cfg(feature = "millionaires-row")]
+            GameEvent::EstablishmentGiven {
+                player,
+                other_player,
+                card,
+            } => println!(
+                "{} gave their {} to {}.",
+                get_player(players, *player).name,
+                get_card_title(*card),
+                get_player(players, *other_player).name
+            ),
+            #[cfg(feature = "millionaires-row")]
+            GameEvent::EstablishmentReturned { player, card } => println!(
+                "{} returned their {} to the supply.",
+                get_player(players, *player).name,
+                get_card_title(*card)
+            ),
+            #[cfg(feature = "millionaires-row")]
+            GameEvent::LandmarkDemolished { player, landmark } => println!(
+                "{} demolished their {}.",
+                get_player(players, *player).name,
+                get_landmark_title(landmark)
+            ),
+            #[cfg(feature = "millionaires-row")]
+            GameEvent::CoinInvested { player, card } => println!(
+                "{} invested 1 coin in their {}.",
+                get_player(players, *player).name,
+                get_card_title(*card)
+            ),
+            GameEvent::TradeUnavailable { player } => println!(
+                "{} has no establishments that can be traded.",
+                get_player(players, *player).name
+            ),
+            GameEvent::TradeDeclined { player } => println!(
+                "{} decided not to trade establishments.",
+                get_player(players, *player).name
+            ),
+            GameEvent::CardBought { card, .. } => println!("Bought the {}.", get_card_title(*card)),
+            GameEvent::LandmarkBuilt { landmark, .. } => {
+                println!("Bought the {} landmark.", get_landmark_title(landmark))
+            }
+            GameEvent::PurchaseSkipped { .. } => println!("Didn't buy anything."),
+            GameEvent::CardRevealed { card } => {
+                println!(
+                    "A {} was revealed in the marketplace.",
+                    get_card_title(*card)
+                )
+            }
+            GameEvent::ActionRejected { error, .. } => println!("Not allowed: {}", error),
+            GameEvent::ExtraTurn { .. } => amusement_park_turn(),
+            GameEvent::GameWon { player } => {
+                player_has_won(get_player(players, *player), self.get_kind_name(*player))
+            }
+        }
+    }
+}
+
+pub fn start_player_turn(player: &Player, kind_name: &str) {
     println!();
-    println!("It's {}'s ({}) turn.", player.name, player.get_kind_name());
+    println!("It's {}'s ({}) turn.", player.name, kind_name);
 }
 
 pub fn roll_result(first: u8, second: Option<u8>, total: u8) {
@@ -69,85 +231,197 @@ pub fn roll_result(first: u8, second: Option<u8>, total: u8) {
     }
 }
 
-pub fn get_player_to_steal_coins_from(
-    players: &Vec<Player>,
-    player_turn: usize,
-    amount: u8,
-) -> usize {
+pub fn get_player_to_steal_coins_from(players: &[Player], player_turn: usize, amount: u8) -> usize {
     println!(
         "Which player would you like to steal {} coins from:",
         amount
     );
-    get_player_except(players, player_turn)
+    select_player(players, &get_legal_steal_targets(players, player_turn))
 }
 
 pub fn get_player_to_trade_establishment_with(
-    players: &Vec<Player>,
+    players: &[Player],
     player_turn: usize,
-) -> (usize, CardKind, CardKind) {
+) -> Option<(usize, CardKind, CardKind)> {
+    let trades = get_legal_trades(players, player_turn);
+    if trades.is_empty() {
+        return None;
+    }
+
     loop {
-        println!("Which player would you like to trade a establishment with:");
-        let other_player_number = get_player_except(players, player_turn);
+        println!("Would you like to trade an establishment? (y, n):");
+        match get_input().trim().to_lowercase().as_str() {
+            "y" => break,
+            "n" => return None,
+            _ => continue,
+        }
+    }
+
+    println!("Which player would you like to trade a establishment with:");
+    let mut other_player_turns: Vec<usize> = trades.iter().map(|trade| trade.0).collect();
+    other_player_turns.dedup();
+    let other_player_turn = select_player(players, &other_player_turns);
+
+    let mut other_player_cards: Vec<CardKind> = trades
+        .iter()
+        .filter(|trade| trade.0 == other_player_turn)
+        .map(|trade| trade.1)
+        .collect();
+    other_player_cards.dedup();
+    let other_player_card =
+        select_card_kind(get_player(players, other_player_turn), &other_player_cards);
+
+    let player_cards: Vec<CardKind> = trades
+        .iter()
+        .filter(|trade| trade.0 == other_player_turn && trade.1 == other_player_card)
+        .map(|trade| trade.2)
+        .collect();
+    let player_card = select_card_kind(get_player(players, player_turn), &player_cards);
+
+    Some((other_player_turn, other_player_card, player_card))
+}
+
+#[cfg(feature = "millionaires-row")]
+pub fn get_establishment_to_give(players: &[Player], player_turn: usize) -> (usize, CardKind) {
+    let gifts = get_legal_gifts(players, player_turn);
+
+    println!("Which player would you like to give an establishment to:");
+    let mut other_player_turns: Vec<usize> = gifts.iter().map(|gift| gift.0).collect();
+    other_player_turns.dedup();
+    let other_player_turn = select_player(players, &other_player_turns);
 
-        let other_player = players
-            .get(other_player_number)
-            .expect("Selected player to not be OOB");
-        let other_player_card = get_non_major_card_kind(&other_player);
+    let player_cards: Vec<CardKind> = gifts
+        .iter()
+        .filter(|gift| gift.0 == other_player_turn)
+        .map(|gift| gift.1)
+        .collect();
+    let player_card = select_card_kind(get_player(players, player_turn), &player_cards);
+
+    (other_player_turn, player_card)
+}
 
-        let current_player = players
-            .get(player_turn)
-            .expect("Selected player to not be OOB");
-        let player_card = get_non_major_card_kind(&current_player);
+#[cfg(feature = "millionaires-row")]
+pub fn get_landmark_to_demolish(player: &Player) -> LandmarkKind {
+    let landmarks = get_legal_demolitions(player);
+    let data = landmarks
+        .iter()
+        .enumerate()
+        .map(|(index, landmark)| {
+            vec![
+                index.to_string(),
+                get_landmark_title(landmark).to_string(),
+                get_landmark_description(landmark).to_string(),
+            ]
+        })
+        .collect();
+    print_table("Select a landmark", vec!["#", "Title", "Description"], data);
+    println!("Which landmark would you like to demolish:");
 
-        return (other_player.turn, other_player_card, player_card);
+    loop {
+        return match get_input().trim().parse::<usize>() {
+            Ok(num) => match landmarks.get(num) {
+                Some(landmark) => landmark.clone(),
+                None => {
+                    println!("Please specify the landmark number:");
+                    continue;
+                }
+            },
+            Err(_) => {
+                println!("Please specify the landmark number:");
+                continue;
+            }
+        };
     }
 }
 
-pub fn share_post_distribution_results(current_coins: u8, before_coins: u8) {
-    let current_coins_i16: i16 = current_coins.into();
-    let before_coins_i16: i16 = before_coins.into();
-    let delta = current_coins_i16 - &before_coins_i16;
-    println!(
-        "Coins: {} ({}{})",
-        current_coins,
-        if delta.is_negative() { "" } else { "+" },
-        delta
-    );
+#[cfg(feature = "millionaires-row")]
+pub fn get_establishment_to_renovate(players: &[Player], player_turn: usize) -> CardKind {
+    println!("Which establishment would you like to close for renovation:");
+    select_card_kind(
+        get_player(players, player_turn),
+        &get_legal_renovations(players),
+    )
+}
+
+#[cfg(feature = "millionaires-row")]
+pub fn get_establishment_to_exhibit(player: &Player) -> Option<CardKind> {
+    loop {
+        println!("Would you like to activate another establishment with the Exhibit Hall? (y, n):");
+        match get_input().trim().to_lowercase().as_str() {
+            "y" => break,
+            "n" => return None,
+            _ => continue,
+        }
+    }
+    Some(select_card_kind(player, &get_legal_exhibits(player)))
+}
+
+#[cfg(feature = "millionaires-row")]
+pub fn ask_invest(player: &Player) -> bool {
+    loop {
+        println!(
+            "You have {} coins. Would you like to invest 1 in your Tech Startup? (y, n):",
+            player.coins
+        );
+        return match get_input().trim().to_lowercase().as_str() {
+            "y" => true,
+            "n" => false,
+            _ => continue,
+        };
+    }
 }
 
 pub fn buy_a_card(
+    players: &[Player],
+    player_turn: usize,
     card_deck: &Deck,
-    player: &Player,
-) -> Option<(Option<CardKind>, Option<LandmarkKind>)> {
-    if player.coins == 0 {
-        return None;
+    config: &GameConfig,
+) -> Purchase {
+    let player = get_player(players, player_turn);
+    if get_legal_purchases(player, card_deck, config) == [Purchase::Pass] {
+        return Purchase::Pass;
     }
 
     println!();
+    println!("You have {} coins.", player.coins);
+    let income = show_income_forecast(players, player_turn);
     println!("Available cards");
 
-    // Print regular cards.
-    let mut index: usize = 0;
-    let data = card_deck
+    // Print the cards in play. In a marketplace, only the face-up piles can be bought.
+    let available_cards: Vec<&CardStack> = card_deck
+        .iter()
+        .filter(|card_stack| {
+            config.is_card_in_play(card_stack.kind)
+                && (config.supply_mode == SupplyMode::Full || card_stack.count > 0)
+        })
+        .collect();
+    // Every option in the order it is listed, so that the number typed in is its index.
+    let mut options: Vec<Purchase> = Vec::new();
+    let data = available_cards
+        .iter()
         .map(|card_stack| {
-            let player_card_count = match player.cards.iter().find(|c| c.kind == card_stack.kind) {
-                Some(c) => c.count,
-                None => 0,
-            };
-            index += 1;
+            let player_card_count = player.cards.get_count(card_stack.kind);
+            options.push(Purchase::Card(card_stack.kind));
             vec![
-                (index - 1).to_string(),
+                (options.len() - 1).to_string(),
                 card_stack.get_title().to_string(),
-                card_stack.get_cost().to_string(),
-                player_card_count.to_string(),
+                card_stack.get_cost_description(),
+                match config.get_card_limit(card_stack.kind) {
+                    Some(limit) => format!("{} of {}", player_card_count, limit),
+                    None => player_card_count.to_string(),
+                },
                 card_stack.count.to_string(),
                 card_stack.get_activation_description().to_string(),
+                format!(
+                    "{:+.2}",
+                    get_income_gain(players, player_turn, income, card_stack.kind)
+                ),
                 card_stack.get_order_title().to_string(),
                 card_stack.get_icon_title().to_string(),
                 card_stack.get_description().to_string(),
             ]
         })
-        .to_vec();
+        .collect();
 
     print_table(
         "Establishments",
@@ -158,6 +432,7 @@ pub fn buy_a_card(
             "Owned",
             "Available",
             "Activation",
+            "Income",
             "Class",
             "Icon",
             "Description",
@@ -166,18 +441,19 @@ pub fn buy_a_card(
     );
 
     // Print unbuilt landmarks.
-    let available_landmarks: Vec<LandmarkKind> = ALL_LANDMARKS
+    let available_landmarks: Vec<LandmarkKind> = config
+        .get_landmarks()
         .into_iter()
-        .filter(|kind| !player.landmarks.contains(&kind))
+        .filter(|kind| !player.landmarks.contains(kind))
         .collect();
     let data = available_landmarks
         .iter()
         .map(|landmark| {
-            index += 1;
+            options.push(Purchase::Landmark(landmark.clone()));
             vec![
-                (index - 1).to_string(),
+                (options.len() - 1).to_string(),
                 get_landmark_title(landmark).to_string(),
-                get_landmark_cost(landmark).to_string(),
+                config.get_landmark_cost(landmark).to_string(),
                 get_landmark_description(landmark).to_string(),
             ]
         })
@@ -189,53 +465,23 @@ pub fn buy_a_card(
 
     loop {
         break match get_input().trim().to_lowercase().as_str() {
-            "n" => None,
+            "n" => Purchase::Pass,
             input => match input.parse::<usize>() {
                 Ok(selected_index) => {
-                    if selected_index < CARD_KIND_COUNT {
-                        let card = card_deck
-                            .get(selected_index)
-                            .expect("Card index to be in bounds.");
-                        if card.count == 0 {
-                            println!(
-                                "Sorry, there are no {} left. Please select another option:",
-                                card.get_title()
-                            );
-                            continue;
-                        }
-
-                        let cost = card.get_cost();
-                        if player.coins < cost {
-                            println!(
-                            "Sorry, you only have {} coins but need {}. Please select another option:",
-                            player.coins,
-                            cost
-                        );
-                            continue;
-                        }
-
-                        Some((Some(card.kind), None))
-                    } else if selected_index - CARD_KIND_COUNT < available_landmarks.len() {
-                        let landmark_index = selected_index - CARD_KIND_COUNT;
-                        let landmark = available_landmarks
-                            .get(landmark_index)
-                            .expect("Landmark index to be in bounds.");
-
-                        let cost = get_landmark_cost(&landmark);
-                        if player.coins < cost {
-                            println!(
-                            "Sorry, you only have {} coins but need {}. Please select another option:",
-                            player.coins,
-                            cost
-                        );
+                    let purchase = match options.get(selected_index) {
+                        Some(purchase) => purchase.clone(),
+                        None => {
+                            println!("Invalid option, please select a number from the list:");
                             continue;
                         }
+                    };
 
-                        Some((None, Some(landmark.clone())))
-                    } else {
-                        println!("Invalid option, please select a number from the list:");
+                    if let Err(error) = check_purchase(player, card_deck, config, &purchase) {
+                        println!("Sorry! {} Please select another option:", error);
                         continue;
                     }
+
+                    purchase
                 }
                 Err(_) => {
                     println!("Please specifiy either a number or \"n\" to skip.");
@@ -246,38 +492,75 @@ pub fn buy_a_card(
     }
 }
 
-pub fn show_purchase_decision(
-    purchase_decision: &Option<(Option<CardKind>, Option<LandmarkKind>)>,
-) {
-    match purchase_decision {
-        Some((card_kind, landmark_kind)) => match card_kind {
-            Some(card) => println!("Bought the {}.", get_card_title(card.clone())),
-            None => println!(
-                "Bought the {} landmark.",
-                get_landmark_title(
-                    &landmark_kind
-                        .clone()
-                        .expect("Expected either a card or a landmark.")
-                )
-            ),
-        },
-        None => println!("Didn't buy anything."),
-    }
+// Prints the coins the player can expect on each turn of a round, and returns their expected
+// income per round.
+fn show_income_forecast(players: &[Player], player_turn: usize) -> f64 {
+    let forecast = get_income_forecast(players, player_turn);
+    let mut data: Vec<Vec<String>> = forecast
+        .own_turn
+        .iter()
+        .map(|choice| {
+            vec![
+                format!(
+                    "Your turn, rolling {}",
+                    get_dice_title(choice.roll_two_dice)
+                ),
+                format!("{:+.2}", choice.expected_coins),
+            ]
+        })
+        .collect();
+    data.extend(forecast.other_turns.iter().map(|(turn, coins)| {
+        vec![
+            format!("{}'s turn", get_player(players, *turn).name),
+            format!("{:+.2}", coins),
+        ]
+    }));
+    let income = forecast.get_round_total();
+    data.push(vec!["Round".to_string(), format!("{:+.2}", income)]);
+    print_table("Expected income", vec!["Turn", "Coins"], data);
+    income
 }
 
-pub fn show_activated_cards(cards: &Vec<PlayerCardStack>) {
-    if cards.is_empty() {
-        println!("No cards activated this turn.");
-    } else {
-        let mut unique_cards = cards.to_vec();
-        unique_cards.sort_by_key(|card| card.card.kind);
-        unique_cards.dedup_by_key(|card| card.card.kind);
+pub fn show_coins_transferred(
+    players: &[Player],
+    from_player_turn: Option<usize>,
+    to_player_turn: Option<usize>,
+    amount: u8,
+) {
+    let get_name = |turn: Option<usize>| match turn {
+        Some(turn) => get_player(players, turn).name.clone(),
+        None => String::from("the bank"),
+    };
+    println!(
+        "{} got {} {} from {}.{}",
+        get_name(to_player_turn),
+        amount,
+        if amount == 1 { "coin" } else { "coins" },
+        get_name(from_player_turn),
+        match to_player_turn {
+            Some(turn) => format!(" Coins: {}", get_player(players, turn).coins),
+            None => String::new(),
+        }
+    );
+}
 
-        print!("Cards activated this turn:");
-        for card in unique_cards.iter() {
-            print!(" {}", card.card.get_title());
+// Asks the player whether they want to save before taking their turn, returning the path to save to.
+pub fn ask_save_path(player: &Player) -> Option<String> {
+    println!();
+    println!(
+        "{}, press enter to take your turn or type \"save <path>\" to save the game:",
+        player.name
+    );
+    loop {
+        let input = get_input();
+        let input = input.trim();
+        if input.is_empty() {
+            return None;
+        }
+        match input.strip_prefix("save ") {
+            Some(path) if !path.trim().is_empty() => return Some(path.trim().to_string()),
+            _ => println!("Please press enter or type \"save <path>\":"),
         }
-        println!();
     }
 }
 
@@ -292,42 +575,80 @@ pub fn ask_reroll() -> bool {
     }
 }
 
+#[cfg(feature = "harbor")]
+pub fn ask_add_two(roll_total: u8) -> bool {
+    loop {
+        println!(
+            "Would you like to use the Harbor to add 2 to your total of {}? (y, n):",
+            roll_total
+        );
+        return match get_input().trim().to_lowercase().as_str() {
+            "y" => true,
+            "n" => false,
+            _ => continue,
+        };
+    }
+}
+
 pub fn amusement_park_turn() {
     println!();
     println!("You rolled doubles and have the Amusement Park, take another turn!");
 }
 
-pub fn player_has_won(player: &Player) {
+pub fn player_has_won(player: &Player, kind_name: &str) {
     println!();
     print!(
         "{} ({}) has won the game! Congratulations!",
-        player.name,
-        player.get_kind_name()
+        player.name, kind_name
+    );
+}
+
+pub fn show_standings(players: &[Player]) {
+    let data = players
+        .iter()
+        .map(|player| {
+            vec![
+                player.name.clone(),
+                player.coins.to_string(),
+                player.cards.count_cards().to_string(),
+                player
+                    .landmarks
+                    .iter()
+                    .map(get_landmark_title)
+                    .collect::<Vec<&str>>()
+                    .join(", "),
+            ]
+        })
+        .collect();
+
+    println!();
+    print_table(
+        "Standings",
+        vec!["Name", "Coins", "Cards", "Landmarks"],
+        data,
     );
 }
 
-fn get_player_except(players: &Vec<Player>, except_player_turn: usize) -> usize {
-    let player_options: Vec<(usize, String, u8)> = players
+fn get_player(players: &[Player], turn: usize) -> &Player {
+    players.get(turn).expect("Player to not be OOB.")
+}
+
+// Asks for one of the given players.
+fn select_player(players: &[Player], player_turns: &[usize]) -> usize {
+    let player_options: Vec<&Player> = player_turns
         .iter()
-        .filter(|player| player.turn != except_player_turn)
-        .map(|player| (player.turn, player.name.clone(), player.coins))
+        .map(|turn| get_player(players, *turn))
         .collect();
 
-    let player_display_options = players
+    let player_display_options = player_options
         .iter()
-        .filter(|player| player.turn != except_player_turn)
         .enumerate()
         .map(|(index, player)| {
             vec![
                 index.to_string(),
                 player.name.clone(),
                 player.coins.to_string(),
-                player
-                    .cards
-                    .map(|card| card.count)
-                    .iter()
-                    .sum::<u8>()
-                    .to_string(),
+                player.cards.count_cards().to_string(),
             ]
         })
         .collect();
@@ -341,7 +662,7 @@ fn get_player_except(players: &Vec<Player>, except_player_turn: usize) -> usize
     loop {
         return match get_input().trim().parse::<usize>() {
             Ok(num) => match player_options.get(num) {
-                Some(player) => player.0,
+                Some(player) => player.turn,
                 None => {
                     println!("Please specify the player number:");
                     continue;
@@ -355,41 +676,24 @@ fn get_player_except(players: &Vec<Player>, except_player_turn: usize) -> usize
     }
 }
 
-fn print_table(header: &str, columns: Vec<&str>, data: Vec<Vec<String>>) {
-    let table = Builder::from(data)
-        .set_columns(columns)
-        .build()
-        .with(Header(header))
-        .with(Style::modern())
-        .with(
-            Modify::new(Segment::all())
-                .with(Alignment::left())
-                .with(Alignment::top()),
-        )
-        .with(Modify::new(Columns::new(0..)).with(MaxWidth::wrapping(70)));
-    println!("{}", table);
-}
-
-fn get_non_major_card_kind(player: &Player) -> CardKind {
-    let card_options: Vec<CardStack> = player
+// Asks for one of the given cards owned by the player.
+fn select_card_kind(player: &Player, card_kinds: &[CardKind]) -> CardKind {
+    let card_options: Vec<&CardStack> = player
         .cards
-        .into_iter()
-        .filter(|card| card.count > 0 && card.get_icon() != CardIcon::Major)
+        .iter()
+        .filter(|card| card_kinds.contains(&card.kind))
         .collect();
 
     let mut index: usize = 0;
     let data = card_options
         .iter()
         .map(|card_stack| {
-            let player_card_count = match player.cards.iter().find(|c| c.kind == card_stack.kind) {
-                Some(c) => c.count,
-                None => 0,
-            };
+            let player_card_count = player.cards.get_count(card_stack.kind);
             index += 1;
             vec![
                 (index - 1).to_string(),
                 card_stack.get_title().to_string(),
-                card_stack.get_cost().to_string(),
+                card_stack.get_cost_description(),
                 player_card_count.to_string(),
                 card_stack.count.to_string(),
                 card_stack.get_activation_description().to_string(),
@@ -434,12 +738,14 @@ fn get_non_major_card_kind(player: &Player) -> CardKind {
     }
 }
 
-fn get_player_kind() -> PlayerKind {
+fn get_player_strategy() -> Box<dyn PlayerStrategy> {
     loop {
-        println!("Human or computer (h, c):");
+        println!("Human, computer, greedy computer or MCTS computer (h, c, g, m):");
         return match get_input().trim().to_lowercase().as_str() {
-            "c" => PlayerKind::RandomAI,
-            "h" => PlayerKind::Human,
+            "c" => Box::new(RandomAI),
+            "g" => Box::new(GreedyAI),
+            "m" => Box::<MctsAI>::default(),
+            "h" => Box::new(Human),
             _ => continue,
         };
     }
@@ -455,24 +761,22 @@ fn get_player_name(player_number: usize) -> String {
     }
 }
 
-fn get_player_count() -> usize {
-    println!("How many players (2-{}):", MAX_PLAYER_COUNT);
+fn get_player_count(max_player_count: usize) -> usize {
+    println!("How many players (2-{}):", max_player_count);
     loop {
-        return match get_input().trim().parse() {
-            Ok(num) => match num {
-                2..=MAX_PLAYER_COUNT => num,
-                _ => {
-                    println!(
-                        "Please specify a player count between 2 and {}: ",
-                        MAX_PLAYER_COUNT
-                    );
-                    continue;
-                }
-            },
+        return match get_input().trim().parse::<usize>() {
+            Ok(num) if (2..=max_player_count).contains(&num) => num,
+            Ok(_) => {
+                println!(
+                    "Please specify a player count between 2 and {}: ",
+                    max_player_count
+                );
+                continue;
+            }
             Err(_) => {
                 println!(
                     "Please specify a number between 2 and {}:",
-                    MAX_PLAYER_COUNT
+                    max_player_count
                 );
                 continue;
             }
@@ -0,0 +1,84 @@
+#[cfg(feature = "millionaires-row")]
+use dice_valley::{game::controller::demolish_landmark, GameError};
+use dice_valley::{
+    game::{config::Expansion, players::create_strategy, replay::replay_game},
+    CardKind, Game, GameConfig, GameEvent, LandmarkKind, Player,
+};
+use std::{cell::RefCell, rc::Rc};
+
+mod common;
+
+use common::{get_players, get_strategies};
+
+#[test]
+fn test_harbor_config() {
+    let config = GameConfig::default();
+    assert_eq!(config.get_landmarks().len(), 4);
+    assert!(config.get_starting_landmarks().is_empty());
+    assert_eq!(config.get_supply_count(CardKind::TunaBoat), 0);
+
+    let config = GameConfig::from_toml("expansions = [\"Harbor\"]").expect("Config to be valid.");
+    assert!(config.expansions.contains(&Expansion::Harbor));
+    assert_eq!(config.get_landmarks().len(), 7);
+    assert_eq!(
+        config.get_starting_landmarks(),
+        vec![LandmarkKind::CityHall]
+    );
+    assert_eq!(config.get_supply_count(CardKind::TunaBoat), 6);
+    assert_eq!(config.get_supply_count(CardKind::TaxOffice), 4);
+}
+
+#[test]
+#[cfg(feature = "millionaires-row")]
+fn test_city_hall_cannot_be_demolished() {
+    let config = get_config();
+    let mut players = get_players(3, &config);
+    assert_eq!(
+        demolish_landmark(&mut players, 0, &LandmarkKind::CityHall),
+        Err(GameError::CannotDemolish(LandmarkKind::CityHall))
+    );
+    assert_eq!(players[0].landmarks, vec![LandmarkKind::CityHall]);
+}
+
+#[test]
+fn test_harbor_game() {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    let config = get_config();
+    let mut game = Game::with_config(get_players(3, &config), get_strategies(3), 21, config)
+        .expect("Config to be valid.");
+    let subscriber_events = Rc::clone(&events);
+    game.subscribe(Box::new(move |event: &GameEvent, _: &[Player]| {
+        subscriber_events.borrow_mut().push(event.clone())
+    }));
+    game.run_to_completion().expect("Game to finish.");
+
+    let winner = game.get_winner().expect("Game to have a winner.");
+    assert_eq!(winner.landmarks.len(), 7);
+    assert!(events.borrow().iter().any(|event| matches!(
+        event,
+        GameEvent::LandmarkActivated {
+            landmark: LandmarkKind::CityHall,
+            ..
+        }
+    )));
+
+    let (mut replayed_game, decisions) = game
+        .record
+        .clone()
+        .into_replay(create_strategy)
+        .expect("Record to be valid.");
+    replay_game(&mut replayed_game, &decisions).expect("Replay to succeed.");
+    assert!(decisions.borrow().is_empty());
+    assert_eq!(replayed_game.winner, game.winner);
+    for (player, replayed_player) in game.players.iter().zip(replayed_game.players.iter()) {
+        assert_eq!(replayed_player.coins, player.coins);
+        assert_eq!(replayed_player.landmarks, player.landmarks);
+    }
+}
+
+fn get_config() -> GameConfig {
+    GameConfig {
+        expansions: [Expansion::Harbor].into(),
+        ..GameConfig::default()
+    }
+}
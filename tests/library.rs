@@ -0,0 +1,119 @@
+#[cfg(feature = "millionaires-row")]
+use dice_valley::LandmarkKind;
+use dice_valley::{
+    game::{controller::create_player_deck, players::random::RandomAI},
+    CardKind, DiceRoll, Game, GameConfig, GameRng, GameView, Player, PlayerStrategy, Purchase,
+};
+
+// A strategy defined outside of the crate that never buys anything.
+struct Miser;
+
+impl PlayerStrategy for Miser {
+    fn get_id(&self) -> &'static str {
+        "miser"
+    }
+
+    fn get_kind_name(&self) -> &'static str {
+        "Miser"
+    }
+
+    fn get_roll_two_dice(&mut self, _view: &GameView, _rng: &mut GameRng) -> bool {
+        false
+    }
+
+    fn ask_reroll(&mut self, _view: &GameView, _roll: DiceRoll, _rng: &mut GameRng) -> bool {
+        false
+    }
+
+    #[cfg(feature = "harbor")]
+    fn ask_add_two(&mut self, _view: &GameView, _roll_total: u8, _rng: &mut GameRng) -> bool {
+        false
+    }
+
+    fn get_trade_establishments(
+        &mut self,
+        _view: &GameView,
+        _owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> Option<(usize, CardKind, CardKind)> {
+        unreachable!("A miser never owns a Business Center.")
+    }
+
+    fn get_player_to_steal_coins_from(
+        &mut self,
+        _view: &GameView,
+        _owner_turn: usize,
+        _amount: u8,
+        _rng: &mut GameRng,
+    ) -> usize {
+        unreachable!("A miser never owns a TV Station.")
+    }
+
+    fn buy_a_card(&mut self, _view: &GameView, _rng: &mut GameRng) -> Purchase {
+        Purchase::Pass
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn ask_invest(&mut self, _view: &GameView, _rng: &mut GameRng) -> bool {
+        unreachable!("A miser never owns a Tech Startup.")
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_give(
+        &mut self,
+        _view: &GameView,
+        _owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> (usize, CardKind) {
+        unreachable!("A miser never owns a Moving Company.")
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_landmark_to_demolish(
+        &mut self,
+        _view: &GameView,
+        _owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> LandmarkKind {
+        unreachable!("A miser never owns a Demolition Company.")
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_renovate(
+        &mut self,
+        _view: &GameView,
+        _owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> CardKind {
+        unreachable!("A miser never owns a Renovation Company.")
+    }
+
+    #[cfg(feature = "millionaires-row")]
+    fn get_establishment_to_exhibit(
+        &mut self,
+        _view: &GameView,
+        _owner_turn: usize,
+        _rng: &mut GameRng,
+    ) -> Option<CardKind> {
+        unreachable!("A miser never owns an Exhibit Hall.")
+    }
+}
+
+#[test]
+fn test_external_strategy() {
+    let players = (0..2)
+        .map(|turn| Player {
+            name: format!("Player {}", turn + 1),
+            turn,
+            cards: create_player_deck(&GameConfig::default()),
+            coins: 0,
+            landmarks: Vec::new(),
+        })
+        .collect();
+    let strategies: Vec<Box<dyn PlayerStrategy>> = vec![Box::new(Miser), Box::new(RandomAI)];
+    let mut game = Game::new(players, strategies, 3).expect("Config to be valid.");
+
+    let winner = game.run_to_completion().expect("Game to finish.");
+    assert_eq!(winner.turn, 1);
+    assert!(game.players[0].landmarks.is_empty());
+}
@@ -0,0 +1,76 @@
+use dice_valley::{
+    game::{
+        config::Expansion,
+        controller::{get_invested_coins, get_legal_investments, invest_coin},
+        players::create_strategy,
+        replay::replay_game,
+    },
+    CardKind, CardStack, Game, GameConfig,
+};
+
+mod common;
+
+use common::{get_players, get_strategies};
+
+#[test]
+fn test_millionaires_row_config() {
+    let config =
+        GameConfig::from_toml("expansions = [\"MillionairesRow\"]").expect("Config to be valid.");
+    assert!(config.expansions.contains(&Expansion::MillionairesRow));
+    assert_eq!(config.get_landmarks().len(), 4);
+    assert!(config.get_starting_landmarks().is_empty());
+    assert_eq!(config.get_supply_count(CardKind::Vineyard), 6);
+    assert_eq!(
+        GameConfig::default().get_supply_count(CardKind::Vineyard),
+        0
+    );
+    assert_eq!(
+        CardStack::new(CardKind::LoanOffice, 1).get_cost_description(),
+        "-5"
+    );
+}
+
+#[test]
+fn test_invest_in_tech_startup() {
+    let config = get_config();
+    let mut players = get_players(3, &config);
+    assert_eq!(get_legal_investments(&players[0]), vec![false]);
+    invest_coin(&mut players, 0).expect_err("Player to not own a Tech Startup.");
+
+    players[0].cards.set_count(CardKind::TechStartup, 1);
+    assert_eq!(get_legal_investments(&players[0]), vec![false, true]);
+    invest_coin(&mut players, 0).expect("Coin to be invested.");
+    invest_coin(&mut players, 0).expect("Coin to be invested.");
+    assert_eq!(get_invested_coins(&players, 0), Ok(2));
+    assert_eq!(players[0].coins, 1);
+}
+
+#[test]
+fn test_millionaires_row_game() {
+    let config = get_config();
+    let mut game = Game::with_config(get_players(3, &config), get_strategies(3), 31, config)
+        .expect("Config to be valid.");
+    game.run_to_completion().expect("Game to finish.");
+    let winner = game.get_winner().expect("Game to have a winner.");
+    assert_eq!(winner.landmarks.len(), 4);
+
+    let (mut replayed_game, decisions) = game
+        .record
+        .clone()
+        .into_replay(create_strategy)
+        .expect("Record to be valid.");
+    replay_game(&mut replayed_game, &decisions).expect("Replay to succeed.");
+    assert!(decisions.borrow().is_empty());
+    assert_eq!(replayed_game.winner, game.winner);
+    for (player, replayed_player) in game.players.iter().zip(replayed_game.players.iter()) {
+        assert_eq!(replayed_player.coins, player.coins);
+        assert_eq!(replayed_player.landmarks, player.landmarks);
+    }
+}
+
+fn get_config() -> GameConfig {
+    GameConfig {
+        expansions: [Expansion::MillionairesRow].into(),
+        ..GameConfig::default()
+    }
+}